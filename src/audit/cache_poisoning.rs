@@ -0,0 +1,150 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{Step, Uses, Workflow};
+use crate::permissions::{resolve_worst_case, DefaultPermissions, EffectivePermissions, Level};
+
+/// Flags `actions/cache` (and cache-enabled `actions/setup-*`) usage in
+/// jobs that also hold elevated `GITHUB_TOKEN` permissions or that look
+/// like a release/publish job. A fork PR can poison a cache entry under
+/// a key or `restore-keys` prefix it's allowed to write, and have that
+/// poisoned entry restored later by a privileged job that trusts it.
+pub struct CachePoisoning;
+
+fn is_cache_action(uses: &str) -> bool {
+    let Some(u) = Uses::parse(uses) else { return false };
+    if !u.owner.eq_ignore_ascii_case("actions") || !u.repo.eq_ignore_ascii_case("cache") {
+        return false;
+    }
+    u.subpath
+        .as_deref()
+        .map(|s| s.eq_ignore_ascii_case("restore") || s.eq_ignore_ascii_case("save"))
+        .unwrap_or(true)
+}
+
+fn is_cache_enabled_setup_action(step: &Step) -> bool {
+    let Some(uses) = &step.uses else { return false };
+    let Some(u) = Uses::parse(uses) else { return false };
+    let is_setup = (u.owner.eq_ignore_ascii_case("actions") && u.repo.to_ascii_lowercase().starts_with("setup-"))
+        || (u.owner.eq_ignore_ascii_case("ruby") && u.repo.eq_ignore_ascii_case("setup-ruby"));
+    if !is_setup {
+        return false;
+    }
+    step.with
+        .get("cache")
+        .is_some_and(|v| !matches!(v, serde_yaml::Value::Bool(false)))
+}
+
+fn grants_any_write(permissions: &EffectivePermissions) -> bool {
+    match permissions {
+        EffectivePermissions::Base(level) => *level == Level::Write,
+        EffectivePermissions::Scoped(map) => map.values().any(|level| *level == Level::Write),
+    }
+}
+
+fn looks_like_release_job(job_id: &str) -> bool {
+    let lower = job_id.to_ascii_lowercase();
+    ["release", "publish", "deploy"].iter().any(|kw| lower.contains(kw))
+}
+
+impl Audit for CachePoisoning {
+    fn ident(&self) -> &'static str {
+        "cache-poisoning"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            let privileged = looks_like_release_job(job_id)
+                || grants_any_write(&resolve_worst_case(workflow, job_id, DefaultPermissions::Restricted));
+            if !privileged {
+                continue;
+            }
+
+            for (idx, step) in job.steps.iter().enumerate() {
+                let uses_cache = step.uses.as_deref().is_some_and(is_cache_action) || is_cache_enabled_setup_action(step);
+                if !uses_cache {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                let has_restore_keys = step.with.contains_key("restore-keys");
+                let desc = if has_restore_keys {
+                    "this job has elevated permissions but restores a cache using `restore-keys` prefix matching, \
+                     which a fork PR can poison by writing its own entry under a matching prefix"
+                } else {
+                    "this job has elevated permissions but restores a cache entry that a less-privileged workflow run may have written"
+                };
+
+                findings.push(
+                    Finding::new(self.ident(), desc)
+                        .with_severity(config.effective_severity(self.ident(), Severity::Medium))
+                        .with_confidence(if has_restore_keys { Confidence::Medium } else { Confidence::Low })
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::step(job_id.clone(), idx),
+                            annotation: "cache restored here".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_cache_restore_keys_in_a_job_with_write_permissions() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\npermissions:\n  contents: write\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/cache@v4\n        with:\n          path: .\n          key: build\n          restore-keys: |\n            build-\n",
+        )
+        .unwrap();
+        let findings = CachePoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn lower_confidence_without_restore_keys() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  release:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/cache@v4\n        with:\n          path: .\n          key: build\n",
+        )
+        .unwrap();
+        let findings = CachePoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn matches_cache_action_regardless_of_owner_repo_casing() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\npermissions:\n  contents: write\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: Actions/Cache@v4\n        with:\n          path: .\n          key: build\n",
+        )
+        .unwrap();
+        let findings = CachePoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn ignores_cache_in_an_unprivileged_job() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  test:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/cache@v4\n        with:\n          path: .\n          key: build\n",
+        )
+        .unwrap();
+        let findings = CachePoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::fix::Fix;
+use crate::models::{Uses, Workflow};
+use crate::triggers::Triggers;
+
+/// Flags `actions/checkout` steps that leave the default
+/// `persist-credentials: true`, which leaves the `GITHUB_TOKEN` on disk
+/// for anything later in the job (including malicious dependencies) to
+/// read.
+pub struct CheckoutPersistCredentials;
+
+fn is_checkout(uses: &str) -> bool {
+    Uses::parse(uses).is_some_and(|u| u.owner.eq_ignore_ascii_case("actions") && u.repo.eq_ignore_ascii_case("checkout"))
+}
+
+fn has_persist_credentials_false(with: &indexmap::IndexMap<String, serde_yaml::Value>) -> bool {
+    matches!(with.get("persist-credentials"), Some(v) if v.as_bool() == Some(false))
+}
+
+/// Locates the end of an existing `with:` key's own line, searching
+/// forward from `uses_pos` (a checkout step's `with:` always follows its
+/// `uses:`), so `persist-credentials: false` can be inserted as a new
+/// child right after it instead of only being addable when `with:` is
+/// missing entirely.
+fn with_key_line_end(raw: &str, uses_pos: usize) -> Option<(usize, String)> {
+    let with_offset = raw[uses_pos..].find("with:")?;
+    let with_pos = uses_pos + with_offset;
+    let line_start = raw[..with_pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = raw[with_pos..].find('\n').map(|i| with_pos + i + 1).unwrap_or(raw.len());
+    let before = &raw[line_start..with_pos];
+    if !before.chars().all(|c| c == ' ' || c == '-') {
+        return None;
+    }
+    let indent = " ".repeat(before.chars().count());
+    Some((line_end, indent))
+}
+
+impl Audit for CheckoutPersistCredentials {
+    fn ident(&self) -> &'static str {
+        "checkout-persist-credentials"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        // A fork-facing trigger means the job that leaves credentials on
+        // disk may itself run attacker-influenced steps, so the risk is
+        // higher than the same finding on a maintainer-only trigger.
+        let fork_facing = Triggers::new(&workflow.on).has_fork_facing_trigger();
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(uses) = &step.uses else { continue };
+                if !is_checkout(uses) || has_persist_credentials_false(&step.with) {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+                let default_severity = if fork_facing { Severity::Medium } else { Severity::Low };
+                let severity = config.effective_severity(self.ident(), default_severity);
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        "checkout step does not set persist-credentials: false",
+                    )
+                    .with_severity(severity)
+                    .with_confidence(Confidence::Medium)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx),
+                        annotation: "credentials persist past this step".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+
+    fn suggest_fixes(&self, workflow: &Workflow, _config: &Config) -> anyhow::Result<Vec<Fix>> {
+        let mut fixes = vec![];
+
+        for job in workflow.jobs.values() {
+            for step in &job.steps {
+                let Some(uses) = &step.uses else { continue };
+                if !is_checkout(uses) || has_persist_credentials_false(&step.with) {
+                    continue;
+                }
+                // Best-effort: anchor on the `uses:` line and insert a
+                // `with:` block right after it, matching its indentation
+                // (the step's `- ` list marker counts as indentation too,
+                // since `with:` must align with `uses:`'s own column, not
+                // the dash's). As in `crate::span`, refuse to guess when
+                // the same `uses:` ref appears more than once - there's
+                // no way to tell which occurrence belongs to this step.
+                if workflow.raw.matches(uses.as_str()).count() > 1 {
+                    continue;
+                }
+                let Some(uses_pos) = workflow.raw.find(uses.as_str()) else { continue };
+                let line_start = workflow.raw[..uses_pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+                let before_uses = &workflow.raw[line_start..uses_pos];
+                let Some(uses_key_offset) = before_uses.find("uses:") else { continue };
+                let indent = " ".repeat(before_uses[..uses_key_offset].chars().count());
+                let line_end = workflow.raw[uses_pos..]
+                    .find('\n')
+                    .map(|i| uses_pos + i + 1)
+                    .unwrap_or(workflow.raw.len());
+
+                if step.with.is_empty() {
+                    fixes.push(Fix {
+                        span: line_end..line_end,
+                        replacement: format!("{indent}with:\n{indent}  persist-credentials: false\n"),
+                        description: "add persist-credentials: false".into(),
+                    });
+                } else if let Some((with_line_end, with_indent)) = with_key_line_end(&workflow.raw, uses_pos) {
+                    fixes.push(Fix {
+                        span: with_line_end..with_line_end,
+                        replacement: format!("{with_indent}  persist-credentials: false\n"),
+                        description: "add persist-credentials: false".into(),
+                    });
+                }
+            }
+        }
+
+        Ok(fixes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::fix;
+
+    #[test]
+    fn adds_with_block_when_step_has_none() {
+        let workflow =
+            Workflow::from_string("w.yml", "jobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n")
+                .unwrap();
+        let fixes = CheckoutPersistCredentials.suggest_fixes(&workflow, &Config::default()).unwrap();
+        assert_eq!(fixes.len(), 1);
+
+        let fixed = fix::apply(&workflow.raw, &fixes).unwrap();
+        let reparsed = Workflow::from_string("w.yml", &fixed).unwrap();
+        let step = &reparsed.jobs["j"].steps[0];
+        assert!(has_persist_credentials_false(&step.with));
+    }
+
+    #[test]
+    fn splices_into_an_existing_non_empty_with_block() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "jobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n        with:\n          fetch-depth: 0\n",
+        )
+        .unwrap();
+        let fixes = CheckoutPersistCredentials.suggest_fixes(&workflow, &Config::default()).unwrap();
+        assert_eq!(fixes.len(), 1);
+
+        let fixed = fix::apply(&workflow.raw, &fixes).unwrap();
+        let reparsed = Workflow::from_string("w.yml", &fixed).unwrap();
+        let step = &reparsed.jobs["j"].steps[0];
+        assert!(has_persist_credentials_false(&step.with));
+        assert_eq!(step.with.get("fetch-depth").and_then(|v| v.as_i64()), Some(0));
+    }
+
+    #[test]
+    fn refuses_to_fix_when_the_same_uses_ref_repeats() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "jobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n      - uses: actions/checkout@v4\n",
+        )
+        .unwrap();
+        let fixes = CheckoutPersistCredentials.suggest_fixes(&workflow, &Config::default()).unwrap();
+        assert!(fixes.is_empty());
+    }
+}
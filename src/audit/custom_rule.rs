@@ -0,0 +1,172 @@
+//! A pattern-based audit driven entirely by config, for house rules that
+//! don't warrant a dedicated Rust audit.
+
+use serde::Deserialize;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::Workflow;
+
+/// One user-defined rule, matched against every step of every job.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CustomRule {
+    /// Stable id for this rule, used like any built-in rule id.
+    pub id: String,
+    pub message: String,
+    #[serde(default)]
+    pub severity: Severity,
+    /// Regex matched against `uses:`.
+    #[serde(default)]
+    pub uses: Option<String>,
+    /// Regex matched against `run:`.
+    #[serde(default)]
+    pub run: Option<String>,
+    /// Regex that must match at least one `with:` value.
+    #[serde(default)]
+    pub with_value: Option<String>,
+}
+
+/// Wraps a single [`CustomRule`] so it can be registered like any other
+/// [`Audit`]; [`crate::registry::default_audits`] instantiates one of
+/// these per rule declared in config.
+pub struct CustomRuleAudit {
+    rule: CustomRule,
+    ident: &'static str,
+}
+
+impl CustomRuleAudit {
+    pub fn new(rule: CustomRule) -> Self {
+        // Rule ids come from user config and must outlive the audit for
+        // the lifetime of the `Audit` trait object.
+        let ident = Box::leak(rule.id.clone().into_boxed_str());
+        Self { rule, ident }
+    }
+}
+
+impl Audit for CustomRuleAudit {
+    fn ident(&self) -> &'static str {
+        self.ident
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        let uses_re = self.rule.uses.as_deref().map(regex::Regex::new).transpose()?;
+        let run_re = self.rule.run.as_deref().map(regex::Regex::new).transpose()?;
+        let with_re = self.rule.with_value.as_deref().map(regex::Regex::new).transpose()?;
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                // Every configured filter must match (AND, not OR) - a
+                // rule with both `uses` and `with_value` set is meant to
+                // narrow to that action's risky usage, not flag every
+                // invocation of the action regardless of its `with:`.
+                let mut matched = true;
+                if let Some(re) = &uses_re {
+                    matched &= step.uses.as_deref().is_some_and(|uses| re.is_match(uses));
+                }
+                if let Some(re) = &run_re {
+                    matched &= step.run.as_deref().is_some_and(|run| re.is_match(run));
+                }
+                if let Some(re) = &with_re {
+                    matched &= step.with.values().filter_map(|v| v.as_str()).any(|v| re.is_match(v));
+                }
+
+                if !matched {
+                    continue;
+                }
+
+                let severity = config.effective_severity(self.ident(), self.rule.severity);
+                findings.push(
+                    Finding::new(self.ident, self.rule.message.clone())
+                        .with_severity(severity)
+                        .with_confidence(Confidence::Medium)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::new()
+                                .with_key("jobs")
+                                .with_key(job_id.clone())
+                                .with_key("steps")
+                                .with_index(idx),
+                            annotation: "matched custom rule".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn rule_audit(rule: CustomRule) -> CustomRuleAudit {
+        CustomRuleAudit::new(rule)
+    }
+
+    #[test]
+    fn requires_both_filters_to_match_when_both_are_set() {
+        let rule = CustomRule {
+            id: "no-risky-with".into(),
+            message: "risky action usage".into(),
+            severity: Severity::Medium,
+            uses: Some("some-org/some-action".into()),
+            run: None,
+            with_value: Some("super-secret-pattern".into()),
+        };
+        let audit = rule_audit(rule);
+
+        let matches_both = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@v1\n        with:\n          token: super-secret-pattern\n",
+        )
+        .unwrap();
+        assert_eq!(audit.audit_workflow(&matches_both, &Config::default()).unwrap().len(), 1);
+
+        // Matches the `uses:` filter but not the `with_value` filter -
+        // AND semantics mean this shouldn't flag.
+        let matches_uses_only = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@v1\n        with:\n          token: harmless\n",
+        )
+        .unwrap();
+        assert!(audit.audit_workflow(&matches_uses_only, &Config::default()).unwrap().is_empty());
+
+        // Matches the `with_value` filter but not the `uses:` filter.
+        let matches_with_only = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: other-org/other-action@v1\n        with:\n          token: super-secret-pattern\n",
+        )
+        .unwrap();
+        assert!(audit.audit_workflow(&matches_with_only, &Config::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn matches_every_step_when_no_filters_are_set() {
+        let rule = CustomRule {
+            id: "flag-everything".into(),
+            message: "house rule".into(),
+            severity: Severity::Low,
+            uses: None,
+            run: None,
+            with_value: None,
+        };
+        let audit = rule_audit(rule);
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo hi\n",
+        )
+        .unwrap();
+        assert_eq!(audit.audit_workflow(&workflow, &Config::default()).unwrap().len(), 1);
+    }
+}
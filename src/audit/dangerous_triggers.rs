@@ -0,0 +1,42 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{trigger_names, Workflow};
+
+/// Flags workflows triggered by `pull_request_target` or `workflow_run`,
+/// which run with elevated context against potentially untrusted code.
+pub struct DangerousTriggers;
+
+impl Audit for DangerousTriggers {
+    fn ident(&self) -> &'static str {
+        "dangerous-triggers"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        let triggers = trigger_names(&workflow.on);
+
+        for trigger in ["pull_request_target", "workflow_run"] {
+            if triggers.iter().any(|t| t == trigger) {
+                if config.is_ignored(self.ident(), &workflow.path, None, None) {
+                    continue;
+                }
+                let severity = config.effective_severity(self.ident(), Severity::Medium);
+                findings.push(
+                    Finding::new(self.ident(), format!("workflow uses the {trigger} trigger"))
+                        .with_severity(severity)
+                        .with_confidence(Confidence::Medium)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::on_trigger(trigger),
+                            annotation: "potentially dangerous trigger".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
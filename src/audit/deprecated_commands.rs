@@ -0,0 +1,137 @@
+use camino::Utf8Path;
+use regex::Regex;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::fix::Fix;
+use crate::models::{effective_shell, Action, StepContainer, Workflow};
+use crate::shell::Shell;
+
+/// Flags use of the deprecated `::set-output`/`::set-env`/`::add-path`
+/// workflow commands, which GitHub disabled by default in 2022. The
+/// commands themselves are shell-agnostic (GitHub Actions parses them
+/// from stdout regardless of interpreter), but the print statement that
+/// emits them differs: `echo` under bash/pwsh/cmd all work, while pwsh
+/// scripts more idiomatically use `Write-Output`.
+pub struct DeprecatedCommands;
+
+fn set_output_re(shell: Shell) -> Regex {
+    Regex::new(&format!(r#"{}\s+"::set-output\s+name=([^:]+)::([^"]*)""#, echo_keyword(shell))).unwrap()
+}
+fn set_env_re(shell: Shell) -> Regex {
+    Regex::new(&format!(r#"{}\s+"::set-env\s+name=([^:]+)::([^"]*)""#, echo_keyword(shell))).unwrap()
+}
+fn add_path_re(shell: Shell) -> Regex {
+    Regex::new(&format!(r#"{}\s+"::add-path::([^"]*)""#, echo_keyword(shell))).unwrap()
+}
+
+fn echo_keyword(shell: Shell) -> &'static str {
+    match shell {
+        Shell::Pwsh => "(?:echo|Write-Output)",
+        Shell::Bash | Shell::Cmd => "echo",
+    }
+}
+
+/// Shared by both [`Audit::audit_workflow`] (once per job) and
+/// [`Audit::audit_action`] (once for the whole composite), so the
+/// regex/shell logic above isn't duplicated per container kind.
+fn findings_for_container(
+    ident: &'static str,
+    path: &Utf8Path,
+    container: &dyn StepContainer,
+    route_prefix: Route,
+    job_id: Option<&str>,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = vec![];
+
+    for (idx, step) in container.steps().iter().enumerate() {
+        let Some(run) = &step.run else { continue };
+        let shell = Shell::parse(container.effective_shell(step));
+        let (set_output, set_env, add_path) = (set_output_re(shell), set_env_re(shell), add_path_re(shell));
+        if !(set_output.is_match(run) || set_env.is_match(run) || add_path.is_match(run)) {
+            continue;
+        }
+        if config.is_ignored(ident, path, job_id, step.id.as_deref()) {
+            continue;
+        }
+        let severity = config.effective_severity(ident, Severity::Medium);
+        findings.push(
+            Finding::new(ident, "run script uses a deprecated workflow command")
+                .with_severity(severity)
+                .with_confidence(Confidence::High)
+                .with_location(SymbolicLocation {
+                    path: path.to_path_buf(),
+                    route: route_prefix.clone().with_index(idx).with_key("run"),
+                    annotation: "deprecated command here".into(),
+                    span: None,
+                }),
+        );
+    }
+
+    findings
+}
+
+impl Audit for DeprecatedCommands {
+    fn ident(&self) -> &'static str {
+        "deprecated-commands"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            let route_prefix = Route::new().with_key("jobs").with_key(job_id.clone()).with_key("steps");
+            findings.extend(findings_for_container(
+                self.ident(),
+                &workflow.path,
+                job,
+                route_prefix,
+                Some(job_id.as_str()),
+                config,
+            ));
+        }
+
+        Ok(findings)
+    }
+
+    fn audit_action(&self, action: &Action, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let route_prefix = Route::new().with_key("runs").with_key("steps");
+        Ok(findings_for_container(self.ident(), &action.path, action, route_prefix, None, config))
+    }
+
+    fn suggest_fixes(&self, workflow: &Workflow, _config: &Config) -> anyhow::Result<Vec<Fix>> {
+        let mut fixes = vec![];
+
+        for job in workflow.jobs.values() {
+            for step in &job.steps {
+                let Some(run) = &step.run else { continue };
+                let shell = Shell::parse(effective_shell(workflow, job, step));
+                let (set_output, set_env, add_path) = (set_output_re(shell), set_env_re(shell), add_path_re(shell));
+                let Some(start) = workflow.raw.find(run.as_str()) else { continue };
+
+                let mut rewritten = run.clone();
+                rewritten = set_output
+                    .replace_all(&rewritten, r#"echo "$1=$2" >> "$GITHUB_OUTPUT""#)
+                    .into_owned();
+                rewritten = set_env
+                    .replace_all(&rewritten, r#"echo "$1=$2" >> "$GITHUB_ENV""#)
+                    .into_owned();
+                rewritten = add_path
+                    .replace_all(&rewritten, r#"echo "$1" >> "$GITHUB_PATH""#)
+                    .into_owned();
+
+                if rewritten != *run {
+                    fixes.push(Fix {
+                        span: start..start + run.len(),
+                        replacement: rewritten,
+                        description: "rewrite deprecated workflow commands".into(),
+                    });
+                }
+            }
+        }
+
+        Ok(fixes)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::matrix::runs_on_candidates;
+use crate::models::Workflow;
+use crate::runner_labels::is_deprecated;
+
+/// Flags jobs that run on a GitHub-hosted runner image GitHub has
+/// announced or completed removal of (e.g. `ubuntu-20.04`, `macos-11`),
+/// which will eventually start failing to schedule at all. Backed by
+/// [`crate::runner_labels`] so this knowledge is shared with other
+/// `runs-on`-aware audits instead of re-deriving it.
+pub struct DeprecatedRunnerImage;
+
+impl Audit for DeprecatedRunnerImage {
+    fn ident(&self) -> &'static str {
+        "deprecated-runner-image"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            let deprecated: Vec<_> = runs_on_candidates(job).into_iter().filter(|l| is_deprecated(l)).collect();
+            if deprecated.is_empty() {
+                continue;
+            }
+            if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                continue;
+            }
+
+            let severity = config.effective_severity(self.ident(), Severity::Medium);
+            for label in deprecated {
+                findings.push(
+                    Finding::new(self.ident(), format!("job `{job_id}` runs on deprecated image `{label}`"))
+                        .with_severity(severity)
+                        .with_confidence(Confidence::High)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::job(job_id.clone()).with_key("runs-on"),
+                            annotation: "deprecated runner image".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::env_resolution::{self_reference_issues, EnvRefIssue};
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::Workflow;
+
+/// Flags `env:` values that reference another name in the *same*
+/// mapping via `${{ env.X }}` in a way that can never resolve: a forward
+/// reference to a name declared later in the same block (GitHub
+/// evaluates `env:` top-to-bottom, so it isn't populated yet), or a
+/// definition cycle. Either way the value is always empty at runtime,
+/// which is easy to miss since the YAML itself looks perfectly sensible.
+pub struct EnvReferences;
+
+fn message(issue: &EnvRefIssue) -> String {
+    match issue {
+        EnvRefIssue::ForwardReference { name, refers_to } => format!(
+            "`{name}` references `env.{refers_to}`, which is declared later in the same env: block and so isn't populated yet"
+        ),
+        EnvRefIssue::Cycle { name } => {
+            format!("`{name}` is part of an env: definition cycle and can never resolve")
+        }
+    }
+}
+
+fn name_of(issue: &EnvRefIssue) -> &str {
+    match issue {
+        EnvRefIssue::ForwardReference { name, .. } => name,
+        EnvRefIssue::Cycle { name } => name,
+    }
+}
+
+fn finding(ident: &'static str, config: &Config, path: &camino::Utf8Path, route: Route, issue: &EnvRefIssue) -> Finding {
+    let severity = config.effective_severity(ident, Severity::Medium);
+    Finding::new(ident, message(issue))
+        .with_severity(severity)
+        .with_confidence(Confidence::High)
+        .with_location(SymbolicLocation {
+            path: path.to_path_buf(),
+            route,
+            annotation: "env: value can never resolve as written".into(),
+            span: None,
+        })
+}
+
+impl Audit for EnvReferences {
+    fn ident(&self) -> &'static str {
+        "env-reference"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for issue in self_reference_issues(&workflow.env) {
+            if config.is_ignored(self.ident(), &workflow.path, None, None) {
+                continue;
+            }
+            let route = Route::new().with_key("env").with_key(name_of(&issue).to_string());
+            findings.push(finding(self.ident(), config, &workflow.path, route, &issue));
+        }
+
+        for (job_id, job) in &workflow.jobs {
+            for issue in self_reference_issues(&job.env) {
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                    continue;
+                }
+                let route = Route::job(job_id.clone()).with_key("env").with_key(name_of(&issue).to_string());
+                findings.push(finding(self.ident(), config, &workflow.path, route, &issue));
+            }
+
+            for (idx, step) in job.steps.iter().enumerate() {
+                for issue in self_reference_issues(&step.env) {
+                    if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                        continue;
+                    }
+                    let route = Route::step(job_id.clone(), idx).with_key("env").with_key(name_of(&issue).to_string());
+                    findings.push(finding(self.ident(), config, &workflow.path, route, &issue));
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_forward_reference_in_job_env() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    env:\n      FULL: ${{ env.BASE }}-v2\n      BASE: v1\n    steps: []\n",
+        )
+        .unwrap();
+        let findings = EnvReferences.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn allows_reference_to_earlier_env_var() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\nenv:\n  BASE: v1\n  FULL: ${{ env.BASE }}-v2\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        )
+        .unwrap();
+        let findings = EnvReferences.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
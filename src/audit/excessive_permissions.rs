@@ -0,0 +1,85 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::fix::Fix;
+use crate::models::Workflow;
+use crate::permissions::{resolve, DefaultPermissions, Level};
+
+/// Flags workflows that grant broad write permissions at the top level,
+/// or jobs whose own `permissions:` block grants `write-all` even when
+/// the workflow-level block doesn't.
+pub struct ExcessivePermissions;
+
+impl Audit for ExcessivePermissions {
+    fn ident(&self) -> &'static str {
+        "excessive-permissions"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        if let Some(crate::models::Permissions::Base(level)) = &workflow.permissions {
+            if level == "write-all" && !config.is_ignored(self.ident(), &workflow.path, None, None) {
+                let severity = config.effective_severity(self.ident(), Severity::High);
+                findings.push(
+                    Finding::new(self.ident(), "workflow grants write-all permissions")
+                        .with_severity(severity)
+                        .with_confidence(Confidence::High)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::new().with_key("permissions"),
+                            annotation: "broad permissions granted here".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        for (job_id, job) in &workflow.jobs {
+            if job.permissions.is_none() {
+                continue;
+            }
+            if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                continue;
+            }
+            let resolved = resolve(workflow, job_id, DefaultPermissions::Restricted);
+            if let crate::permissions::EffectivePermissions::Base(Level::Write) = resolved {
+                let severity = config.effective_severity(self.ident(), Severity::High);
+                findings.push(
+                    Finding::new(self.ident(), format!("job `{job_id}` grants write-all permissions"))
+                        .with_severity(severity)
+                        .with_confidence(Confidence::High)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::job(job_id.clone()).with_key("permissions"),
+                            annotation: "broad permissions granted here".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+
+    fn suggest_fixes(&self, workflow: &Workflow, _config: &Config) -> anyhow::Result<Vec<Fix>> {
+        let Some(crate::models::Permissions::Base(level)) = &workflow.permissions else {
+            return Ok(vec![]);
+        };
+        if level != "write-all" {
+            return Ok(vec![]);
+        }
+
+        // Best-effort: locate the literal `permissions: write-all` line.
+        // A real implementation would carry the span from the YAML parse.
+        let needle = "permissions: write-all";
+        let Some(start) = workflow.raw.find(needle) else {
+            return Ok(vec![]);
+        };
+        Ok(vec![Fix {
+            span: start..start + needle.len(),
+            replacement: "permissions:\n  contents: read".into(),
+            description: "replace write-all with least-privilege contents: read".into(),
+        }])
+    }
+}
@@ -0,0 +1,109 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{CallSecrets, Uses, Workflow};
+
+/// Flags reusable-workflow calls that use `secrets: inherit` when the
+/// callee lives outside the calling repository/owner, which hands the
+/// entire secrets context to third-party code rather than just the
+/// secrets the callee actually needs. Local (`./...`) callees are
+/// exempt, since they're part of the same repository.
+pub struct ExternalSecretsInherit;
+
+impl Audit for ExternalSecretsInherit {
+    fn ident(&self) -> &'static str {
+        "external-secrets-inherit"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            let Some(call) = &job.uses else { continue };
+            if call.starts_with("./") {
+                continue;
+            }
+            if !matches!(job.secrets, Some(CallSecrets::Inherit(_))) {
+                continue;
+            }
+            let Some(callee) = Uses::parse(call) else { continue };
+            if config.is_trusted_owner(&callee.owner, &callee.repo) {
+                continue;
+            }
+            if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                continue;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.ident(),
+                    format!(
+                        "job `{job_id}` calls external reusable workflow `{call}` with `secrets: inherit`, \
+                         handing it this workflow's entire secrets context"
+                    ),
+                )
+                .with_severity(config.effective_severity(self.ident(), Severity::High))
+                .with_confidence(Confidence::High)
+                .with_location(SymbolicLocation {
+                    path: workflow.path.clone(),
+                    route: Route::job(job_id.clone()).with_key("secrets"),
+                    annotation: "secrets inherited by external callee here".into(),
+                    span: None,
+                }),
+            );
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_secrets_inherit_into_an_external_callee() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  call:\n    uses: some-org/some-repo/.github/workflows/build.yml@v1\n    secrets: inherit\n",
+        )
+        .unwrap();
+        let findings = ExternalSecretsInherit.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn ignores_local_callee() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  call:\n    uses: ./.github/workflows/build.yml\n    secrets: inherit\n",
+        )
+        .unwrap();
+        let findings = ExternalSecretsInherit.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_trusted_owner() {
+        let config = Config::from_str("trusted-owners:\n  - some-org\n").unwrap();
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  call:\n    uses: some-org/some-repo/.github/workflows/build.yml@v1\n    secrets: inherit\n",
+        )
+        .unwrap();
+        let findings = ExternalSecretsInherit.audit_workflow(&workflow, &config).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_explicit_secrets_map() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  call:\n    uses: some-org/some-repo/.github/workflows/build.yml@v1\n    secrets:\n      token: ${{ secrets.TOKEN }}\n",
+        )
+        .unwrap();
+        let findings = ExternalSecretsInherit.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
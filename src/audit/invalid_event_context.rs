@@ -0,0 +1,91 @@
+use regex::Regex;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::event_schema::is_valid_event_field;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{trigger_names, Workflow};
+
+fn expr_re() -> Regex {
+    Regex::new(r"\$\{\{\s*([^}]+?)\s*\}\}").unwrap()
+}
+
+/// Flags `github.event.*` context accesses that can't be populated under
+/// any of the workflow's triggers - e.g. reading
+/// `github.event.pull_request.body` in a workflow that only runs `on:
+/// push`. These are always empty at runtime, so they're either a
+/// leftover from a trigger that was since changed or a copy-pasted
+/// condition that never did what the author thought. A correctness
+/// finding, not a security one.
+pub struct InvalidEventContext;
+
+impl Audit for InvalidEventContext {
+    fn ident(&self) -> &'static str {
+        "invalid-event-context"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let re = expr_re();
+        let triggers = trigger_names(&workflow.on);
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(run) = &step.run else { continue };
+                for capture in re.captures_iter(run) {
+                    let expr = capture[1].trim();
+                    if is_valid_event_field(&triggers, expr) {
+                        continue;
+                    }
+                    if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                        continue;
+                    }
+                    let severity = config.effective_severity(self.ident(), Severity::Medium);
+                    findings.push(
+                        Finding::new(
+                            self.ident(),
+                            format!("`{expr}` is never populated under this workflow's triggers"),
+                        )
+                        .with_severity(severity)
+                        .with_confidence(Confidence::Medium)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::step(job_id.clone(), idx).with_key("run"),
+                            annotation: "always empty given this workflow's triggers".into(),
+                            span: None,
+                        }),
+                    );
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_pr_body_access_on_push() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo \"${{ github.event.pull_request.body }}\"\n",
+        )
+        .unwrap();
+        let findings = InvalidEventContext.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn does_not_flag_matching_trigger() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request_target\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo \"${{ github.event.pull_request.body }}\"\n",
+        )
+        .unwrap();
+        let findings = InvalidEventContext.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::Workflow;
+
+/// Flags `needs:` references to jobs that don't exist, and dependency
+/// cycles, both of which GitHub Actions rejects at workflow-parse time
+/// but are easy to introduce while refactoring a large workflow.
+pub struct JobGraphAudit;
+
+impl Audit for JobGraphAudit {
+    fn ident(&self) -> &'static str {
+        "job-dependency-graph"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let graph = workflow.job_graph();
+
+        for (job_id, missing) in graph.missing_dependencies() {
+            if config.is_ignored(self.ident(), &workflow.path, Some(&job_id), None) {
+                continue;
+            }
+            let severity = config.effective_severity(self.ident(), Severity::High);
+            findings.push(
+                Finding::new(self.ident(), format!("job `{job_id}` needs undefined job `{missing}`"))
+                    .with_severity(severity)
+                    .with_confidence(Confidence::High)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::job(job_id).with_key("needs"),
+                        annotation: "references a job that isn't defined".into(),
+                        span: None,
+                    }),
+            );
+        }
+
+        for cycle in graph.cycles() {
+            let job_id = cycle.first().cloned().unwrap_or_default();
+            if config.is_ignored(self.ident(), &workflow.path, Some(&job_id), None) {
+                continue;
+            }
+            let severity = config.effective_severity(self.ident(), Severity::High);
+            findings.push(
+                Finding::new(self.ident(), format!("dependency cycle: {}", cycle.join(" -> ")))
+                    .with_severity(severity)
+                    .with_confidence(Confidence::High)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::job(job_id).with_key("needs"),
+                        annotation: "part of a needs: cycle".into(),
+                        span: None,
+                    }),
+            );
+        }
+
+        Ok(findings)
+    }
+}
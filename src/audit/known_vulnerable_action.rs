@@ -0,0 +1,121 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{Uses, Workflow};
+use crate::osv::{find_advisory, Match};
+
+/// Flags `uses:` references matching a known-vulnerable or malicious
+/// action, per the OSV-format feed loaded from `config.osv_feed_path`.
+/// A no-op without a configured feed - there's nothing to check against.
+pub struct KnownVulnerableAction;
+
+/// Every `uses:` in `workflow` that matches an advisory in
+/// `config.osv_advisories`, alongside the matched advisory. Exposed
+/// separately from [`Audit::audit_workflow`] so `zizmor export-osv` can
+/// reuse the same matching logic to build an OSV document instead of
+/// [`Finding`]s.
+pub fn matches(workflow: &Workflow, config: &Config) -> Vec<Match> {
+    let mut found = vec![];
+    for job in workflow.jobs.values() {
+        for step in &job.steps {
+            let Some(raw) = &step.uses else { continue };
+            let Some(uses) = Uses::parse(raw) else { continue };
+            let owner_repo = uses.owner_repo();
+            if let Some(advisory) = find_advisory(&config.osv_advisories, &owner_repo, &uses.git_ref) {
+                found.push(Match {
+                    owner_repo,
+                    git_ref: uses.git_ref.clone(),
+                    advisory: advisory.clone(),
+                });
+            }
+        }
+    }
+    found
+}
+
+impl Audit for KnownVulnerableAction {
+    fn ident(&self) -> &'static str {
+        "known-vulnerable-action"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        if config.osv_advisories.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut findings = vec![];
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(raw) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw) else { continue };
+                let Some(advisory) = find_advisory(&config.osv_advisories, &uses.owner_repo(), &uses.git_ref) else { continue };
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                let severity = config.effective_severity(self.ident(), Severity::High);
+                findings.push(
+                    Finding::new(self.ident(), format!("matches known-vulnerable advisory {}: {}", advisory.id, advisory.summary))
+                        .with_severity(severity)
+                        .with_confidence(Confidence::High)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::step(job_id.clone(), idx).with_key("uses"),
+                            annotation: "matches a known-vulnerable action advisory".into(),
+                            span: None,
+                        }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::osv::Advisory;
+
+    fn config_with_advisory() -> Config {
+        let mut config = Config::default();
+        config.osv_advisories = vec![serde_json::from_str::<Advisory>(
+            r#"{"id": "GHSA-xxxx", "summary": "backdoored release", "affected": [{"package": {"name": "evil/action", "ecosystem": "GitHub Actions"}, "versions": ["v1"]}]}"#,
+        )
+        .unwrap()];
+        config
+    }
+
+    #[test]
+    fn flags_matching_advisory() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: evil/action@v1\n",
+        )
+        .unwrap();
+        let findings = KnownVulnerableAction.audit_workflow(&workflow, &config_with_advisory()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn allows_non_matching_ref() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: evil/action@v2\n",
+        )
+        .unwrap();
+        let findings = KnownVulnerableAction.audit_workflow(&workflow, &config_with_advisory()).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn no_feed_configured_is_a_no_op() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: evil/action@v1\n",
+        )
+        .unwrap();
+        let findings = KnownVulnerableAction.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
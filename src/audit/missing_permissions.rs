@@ -0,0 +1,123 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{trigger_names, Workflow};
+
+/// Flags jobs that rely entirely on the repository's default
+/// `GITHUB_TOKEN` permissions because neither the workflow nor the job
+/// declares its own `permissions:` block. Unlike
+/// [`crate::audit::excessive_permissions`], which flags permissions that
+/// are declared too broadly, this flags the absence of a declaration at
+/// all - the default varies by repository/org setting and isn't visible
+/// from the workflow file itself.
+pub struct MissingPermissions;
+
+fn severity_for(triggers: &[String]) -> Severity {
+    if triggers.iter().any(|t| t == "pull_request_target") {
+        Severity::High
+    } else if triggers.iter().any(|t| t == "workflow_run") {
+        Severity::Medium
+    } else {
+        Severity::Low
+    }
+}
+
+impl Audit for MissingPermissions {
+    fn ident(&self) -> &'static str {
+        "missing-permissions"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        if workflow.permissions.is_some() {
+            return Ok(findings);
+        }
+
+        let triggers = trigger_names(&workflow.on);
+        let severity = config.effective_severity(self.ident(), severity_for(&triggers));
+
+        for (job_id, job) in &workflow.jobs {
+            if job.permissions.is_some() {
+                continue;
+            }
+            if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                continue;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.ident(),
+                    format!("job `{job_id}` declares no `permissions:` and falls back to the repository's default `GITHUB_TOKEN` permissions"),
+                )
+                .with_severity(severity)
+                .with_confidence(Confidence::High)
+                .with_location(SymbolicLocation {
+                    path: workflow.path.clone(),
+                    route: Route::job(job_id.clone()),
+                    annotation: "relies on default token permissions".into(),
+                    span: None,
+                }),
+            );
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_job_with_no_permissions_anywhere() {
+        let workflow =
+            Workflow::from_string("w.yml", "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n").unwrap();
+        let findings = MissingPermissions.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Low);
+    }
+
+    #[test]
+    fn escalates_severity_for_pull_request_target() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request_target\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        )
+        .unwrap();
+        let findings = MissingPermissions.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::High);
+    }
+
+    #[test]
+    fn escalates_severity_for_workflow_run() {
+        let workflow =
+            Workflow::from_string("w.yml", "on: workflow_run\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n")
+                .unwrap();
+        let findings = MissingPermissions.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings[0].severity, Severity::Medium);
+    }
+
+    #[test]
+    fn ignores_job_when_workflow_declares_permissions() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\npermissions:\n  contents: read\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        )
+        .unwrap();
+        let findings = MissingPermissions.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_job_that_declares_its_own_permissions() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    permissions:\n      contents: read\n    runs-on: ubuntu-latest\n    steps: []\n",
+        )
+        .unwrap();
+        let findings = MissingPermissions.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
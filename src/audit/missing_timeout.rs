@@ -0,0 +1,104 @@
+use serde::Deserialize;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::fix::Fix;
+use crate::models::Workflow;
+
+/// Per-rule options for `missing-timeout`, set via
+/// `rule-options.missing-timeout` in config.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MissingTimeoutOptions {
+    /// Jobs under this runtime don't need an explicit timeout to be
+    /// considered fine; defaults to 0 (always require one).
+    #[serde(default, rename = "max-minutes")]
+    pub max_minutes: u32,
+    /// Value `--fix` inserts when a job has no timeout at all.
+    #[serde(default = "default_fix_minutes", rename = "fix-minutes")]
+    pub fix_minutes: u32,
+}
+
+fn default_fix_minutes() -> u32 {
+    15
+}
+
+impl Default for MissingTimeoutOptions {
+    fn default() -> Self {
+        Self {
+            max_minutes: 0,
+            fix_minutes: default_fix_minutes(),
+        }
+    }
+}
+
+/// Flags jobs with no `timeout-minutes`, which default to GitHub's
+/// 360-minute cap and can run away silently on a hung process.
+pub struct MissingTimeout;
+
+impl Audit for MissingTimeout {
+    fn ident(&self) -> &'static str {
+        "missing-timeout"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let options: MissingTimeoutOptions = config.rule_options(self.ident())?.unwrap_or_default();
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            let has_timeout = job.timeout_minutes.is_some_and(|m| m > options.max_minutes);
+            if has_timeout {
+                continue;
+            }
+            if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                continue;
+            }
+
+            let severity = config.effective_severity(self.ident(), Severity::Low);
+            findings.push(
+                Finding::new(self.ident(), format!("job `{job_id}` has no timeout-minutes set"))
+                    .with_severity(severity)
+                    .with_confidence(Confidence::High)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::job(job_id.clone()),
+                        annotation: "job has no timeout".into(),
+                        span: None,
+                    }),
+            );
+        }
+
+        Ok(findings)
+    }
+
+    fn suggest_fixes(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Fix>> {
+        let options: MissingTimeoutOptions = config.rule_options(self.ident())?.unwrap_or_default();
+        let mut fixes = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            if job.timeout_minutes.is_some_and(|m| m > options.max_minutes) {
+                continue;
+            }
+            // Insert `timeout-minutes:` directly after `runs-on:`, which
+            // is where this repo's own workflows already put it.
+            let Some(runs_on) = &job.runs_on else { continue };
+            let runs_on_str = serde_yaml::to_string(runs_on).unwrap_or_default();
+            let runs_on_str = runs_on_str.trim();
+            let Some(pos) = workflow.raw.find(runs_on_str) else { continue };
+            let line_start = workflow.raw[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+            let indent: String = workflow.raw[line_start..pos]
+                .chars()
+                .take_while(|c| c.is_whitespace())
+                .collect();
+            let line_end = workflow.raw[pos..].find('\n').map(|i| pos + i + 1).unwrap_or(workflow.raw.len());
+
+            fixes.push(Fix {
+                span: line_end..line_end,
+                replacement: format!("{indent}timeout-minutes: {}\n", options.fix_minutes),
+                description: format!("insert timeout-minutes: {} on job `{job_id}`", options.fix_minutes),
+            });
+        }
+
+        Ok(fixes)
+    }
+}
@@ -0,0 +1,96 @@
+//! Audits: self-contained checks that walk a [`Workflow`] and emit
+//! [`Finding`]s.
+
+pub mod cache_poisoning;
+pub mod checkout_persist_credentials;
+pub mod custom_rule;
+pub mod dangerous_triggers;
+pub mod deprecated_commands;
+pub mod deprecated_runner_image;
+pub mod env_references;
+pub mod excessive_permissions;
+pub mod external_secrets_inherit;
+pub mod invalid_event_context;
+pub mod job_graph;
+pub mod known_vulnerable_action;
+pub mod missing_permissions;
+pub mod missing_timeout;
+pub mod overbroad_concurrency;
+pub mod pin_comment;
+pub mod pull_request_target_checkout;
+pub mod reusable_workflow_call;
+pub mod script_rule;
+pub mod secret_in_logs;
+pub mod secrets_to_unpinned_uses;
+pub mod step_references;
+pub mod template_injection;
+pub mod unpinned_uses;
+pub mod unreachable_code;
+pub mod workflow_run_artifact;
+
+use crate::config::Config;
+use crate::finding::Finding;
+use crate::fix::Fix;
+use crate::models::{Action, Workflow};
+use crate::persona::Persona;
+
+/// A single, independently-registered check.
+///
+/// `Send + Sync` so audits can be run concurrently across a `&[Box<dyn
+/// Audit>]`; every built-in audit is a stateless struct (or holds only
+/// thread-safe state like a compiled [`regex::Regex`]), so this is
+/// free in practice.
+pub trait Audit: Send + Sync {
+    /// Stable rule id, used in config, output, and `--only`/`--ignore`.
+    fn ident(&self) -> &'static str;
+
+    /// The minimum persona under which this audit runs by default.
+    /// Most audits are useful to everyone, so this defaults to
+    /// [`Persona::Regular`].
+    fn persona(&self) -> Persona {
+        Persona::Regular
+    }
+
+    /// Whether this audit (or its `--fix` support) can make use of
+    /// network access when it's available, rather than running fully
+    /// offline. Most audits are self-contained and can leave this `false`.
+    fn needs_network(&self) -> bool {
+        false
+    }
+
+    /// Whether this audit needs exclusive access to some shared,
+    /// mutable state (a cache, a connection pool, ...) and therefore
+    /// can't run concurrently with other audits. `zizmor::run_audits_parallel`
+    /// runs audits that answer `true` here serially, interleaved with
+    /// the rest running concurrently; no built-in audit currently needs
+    /// this, but it's the seam for one that does.
+    fn needs_exclusive_state(&self) -> bool {
+        false
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>>;
+
+    /// Audits a standalone composite action file (`action.yml`). Most
+    /// step-level audits that also make sense outside a workflow context
+    /// (deprecated commands, unpinned `uses:`, ...) override this; audits
+    /// that only make sense at the workflow level (triggers, permissions,
+    /// job graphs, ...) can leave this as the default no-op.
+    fn audit_action(&self, _action: &Action, _config: &Config) -> anyhow::Result<Vec<Finding>> {
+        Ok(vec![])
+    }
+
+    /// Cross-workflow audits (currently just reusable-workflow call
+    /// binding) need to see every workflow in the scan set at once,
+    /// rather than one at a time. Most audits are self-contained per
+    /// workflow and can leave this as the default no-op.
+    fn audit_workflow_set(&self, _workflows: &[Workflow], _config: &Config) -> anyhow::Result<Vec<Finding>> {
+        Ok(vec![])
+    }
+
+    /// Proposes fixes for this audit's own findings against `workflow`,
+    /// for `--fix`. Most audits don't have a safe, mechanical fix and
+    /// can leave this as the default empty list.
+    fn suggest_fixes(&self, _workflow: &Workflow, _config: &Config) -> anyhow::Result<Vec<Fix>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,87 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{Concurrency, Workflow};
+use crate::reachability::Tri;
+
+/// Flags a `concurrency:` group whose name is a constant string (no
+/// `${{ ... }}` variation per ref/PR/job) combined with
+/// `cancel-in-progress: true`: every run of the workflow shares the same
+/// group, so a new run cancels whatever unrelated run - on a different
+/// branch, PR, or job - happened to be in flight under that same literal
+/// name, rather than just superseding its own prior run.
+pub struct OverbroadConcurrency;
+
+fn check(
+    ident: &'static str,
+    concurrency: &Concurrency,
+    route: Route,
+    path: &camino::Utf8Path,
+    job_id: Option<&str>,
+    config: &Config,
+) -> Option<Finding> {
+    if !concurrency.is_constant_group() || concurrency.cancels_in_progress() != Tri::True {
+        return None;
+    }
+    if config.is_ignored(ident, path, job_id, None) {
+        return None;
+    }
+
+    let severity = config.effective_severity(ident, Severity::Medium);
+    Some(
+        Finding::new(
+            ident,
+            format!(
+                "concurrency group `{}` is a constant string with cancel-in-progress enabled, so unrelated runs will cancel each other",
+                concurrency.group()
+            ),
+        )
+        .with_severity(severity)
+        .with_confidence(Confidence::High)
+        .with_location(SymbolicLocation {
+            path: path.to_path_buf(),
+            route,
+            annotation: "concurrency group never varies between runs".into(),
+            span: None,
+        }),
+    )
+}
+
+impl Audit for OverbroadConcurrency {
+    fn ident(&self) -> &'static str {
+        "overbroad-concurrency"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        if let Some(concurrency) = &workflow.concurrency {
+            if let Some(finding) = check(
+                self.ident(),
+                concurrency,
+                Route::new().with_key("concurrency"),
+                &workflow.path,
+                None,
+                config,
+            ) {
+                findings.push(finding);
+            }
+        }
+
+        for (job_id, job) in &workflow.jobs {
+            let Some(concurrency) = &job.concurrency else { continue };
+            if let Some(finding) = check(
+                self.ident(),
+                concurrency,
+                Route::new().with_key("jobs").with_key(job_id.clone()).with_key("concurrency"),
+                &workflow.path,
+                Some(job_id.as_str()),
+                config,
+            ) {
+                findings.push(finding);
+            }
+        }
+
+        Ok(findings)
+    }
+}
@@ -0,0 +1,95 @@
+use crate::audit::Audit;
+use crate::comments::comment_on_line_of;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{Uses, Workflow};
+
+/// `unpinned-uses --fix` (and conscientious authors doing it by hand)
+/// annotate a SHA-pinned `uses:` with a `# vX.Y.Z`-style trailing
+/// comment recording which ref the SHA was resolved from, since the SHA
+/// itself doesn't say. Flags that comment when it's there but doesn't
+/// look like a version at all, which usually means it's stale -
+/// left over from a previous pin that was since re-resolved to a
+/// different SHA without updating the comment next to it.
+pub struct PinComment;
+
+/// Whether `text` looks like a version annotation, e.g. `v4`, `4.1.0`,
+/// `v4.1.0-beta`.
+fn looks_like_version(text: &str) -> bool {
+    let text = text.strip_prefix('v').unwrap_or(text);
+    text.split(['.', '-']).next().is_some_and(|first| !first.is_empty() && first.chars().all(|c| c.is_ascii_digit()))
+}
+
+impl Audit for PinComment {
+    fn ident(&self) -> &'static str {
+        "pin-comment-mismatch"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let comments = workflow.comments();
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(raw_uses) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw_uses) else { continue };
+                if uses.unpinned() {
+                    continue;
+                }
+                let Some(pos) = workflow.raw.find(raw_uses.as_str()) else { continue };
+                let Some(comment) = comment_on_line_of(&workflow.raw, &comments, pos) else { continue };
+                if looks_like_version(&comment.text) {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                let severity = config.effective_severity(self.ident(), Severity::Informational);
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        format!("`# {}` next to a SHA-pinned action doesn't look like a version annotation", comment.text),
+                    )
+                    .with_severity(severity)
+                    .with_confidence(Confidence::Low)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx).with_key("uses"),
+                        annotation: "stale or malformed pin comment".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_non_version_comment_on_sha_pin() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@0000000000000000000000000000000000000000 # oops\n",
+        )
+        .unwrap();
+        let findings = PinComment.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn allows_version_comment_on_sha_pin() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@0000000000000000000000000000000000000000 # v4.1.0\n",
+        )
+        .unwrap();
+        let findings = PinComment.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{trigger_names, Uses, Workflow};
+
+/// Flags `pull_request_target` workflows that check out the PR head
+/// (`actions/checkout` with a `ref:` pointed at
+/// `github.event.pull_request.head.sha`/`head.ref`) and then run a
+/// later step in the same job - the classic "pwn request" pattern,
+/// where untrusted fork code runs with `pull_request_target`'s elevated
+/// token and secrets. This is distinct from - and higher-confidence
+/// than - the generic [`crate::audit::dangerous_triggers`] finding,
+/// which fires on the trigger alone with no evidence the PR's code is
+/// actually reached.
+pub struct PullRequestTargetCheckout;
+
+fn is_checkout(uses: &str) -> bool {
+    Uses::parse(uses).is_some_and(|u| u.owner.eq_ignore_ascii_case("actions") && u.repo.eq_ignore_ascii_case("checkout"))
+}
+
+fn checks_out_pr_head(with: &indexmap::IndexMap<String, serde_yaml::Value>) -> bool {
+    let Some(git_ref) = with.get("ref").and_then(|v| v.as_str()) else {
+        return false;
+    };
+    git_ref.contains("pull_request.head.sha") || git_ref.contains("pull_request.head.ref")
+}
+
+impl Audit for PullRequestTargetCheckout {
+    fn ident(&self) -> &'static str {
+        "pull-request-target-checkout"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        if !trigger_names(&workflow.on).iter().any(|t| t == "pull_request_target") {
+            return Ok(findings);
+        }
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(uses) = &step.uses else { continue };
+                if !is_checkout(uses) || !checks_out_pr_head(&step.with) {
+                    continue;
+                }
+                // A checkout of the PR head is only exploitable if
+                // something later in the job actually runs it; a
+                // checkout-only job (e.g. for a diff comment) isn't.
+                let runs_something_after = job.steps[idx + 1..].iter().any(|s| s.run.is_some() || s.uses.is_some());
+                if !runs_something_after {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        "pull_request_target workflow checks out and builds/runs the PR head, \
+                         giving untrusted fork code access to this workflow's elevated token and secrets",
+                    )
+                    .with_severity(config.effective_severity(self.ident(), Severity::High))
+                    .with_confidence(Confidence::High)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx).with_key("with").with_key("ref"),
+                        annotation: "checks out attacker-controlled PR head under pull_request_target".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_checkout_of_pr_head_followed_by_a_run_step() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request_target\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n        with:\n          ref: ${{ github.event.pull_request.head.sha }}\n      - run: npm test\n",
+        )
+        .unwrap();
+        let findings = PullRequestTargetCheckout.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn matches_checkout_regardless_of_owner_repo_casing() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request_target\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: Actions/Checkout@v4\n        with:\n          ref: ${{ github.event.pull_request.head.sha }}\n      - run: npm test\n",
+        )
+        .unwrap();
+        let findings = PullRequestTargetCheckout.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn ignores_checkout_only_job_with_nothing_after() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request_target\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n        with:\n          ref: ${{ github.event.pull_request.head.sha }}\n",
+        )
+        .unwrap();
+        let findings = PullRequestTargetCheckout.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_checkout_without_pull_request_target_trigger() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n        with:\n          ref: ${{ github.event.pull_request.head.sha }}\n      - run: npm test\n",
+        )
+        .unwrap();
+        let findings = PullRequestTargetCheckout.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
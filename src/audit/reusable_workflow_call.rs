@@ -0,0 +1,59 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::Workflow;
+use crate::reusable::{check_binding, resolve_callee, BindingIssue};
+
+/// Flags reusable-workflow calls (`jobs.<id>.uses:` pointing at a local
+/// workflow) that don't match the callee's declared `workflow_call`
+/// inputs/secrets: missing required inputs, inputs the callee never
+/// declared, and the secrets equivalent. Only checkable when the callee
+/// is part of the same scan set - remote callees are skipped.
+pub struct ReusableWorkflowCall;
+
+impl Audit for ReusableWorkflowCall {
+    fn ident(&self) -> &'static str {
+        "reusable-workflow-call"
+    }
+
+    fn audit_workflow(&self, _workflow: &Workflow, _config: &Config) -> anyhow::Result<Vec<Finding>> {
+        Ok(vec![])
+    }
+
+    fn audit_workflow_set(&self, workflows: &[Workflow], config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for caller in workflows {
+            for (job_id, job) in &caller.jobs {
+                let Some(call) = &job.uses else { continue };
+                let Some(callee) = resolve_callee(&caller.path, call, workflows) else { continue };
+                if config.is_ignored(self.ident(), &caller.path, Some(job_id), None) {
+                    continue;
+                }
+
+                for issue in check_binding(job, callee) {
+                    let severity = config.effective_severity(self.ident(), Severity::Medium);
+                    let message = match &issue {
+                        BindingIssue::MissingRequiredInput { name } => format!("missing required input `{name}`"),
+                        BindingIssue::UnknownInput { name } => format!("input `{name}` isn't declared by the callee"),
+                        BindingIssue::UnknownSecret { name } => format!("secret `{name}` isn't declared by the callee"),
+                        BindingIssue::MissingRequiredSecret { name } => format!("missing required secret `{name}`"),
+                    };
+                    findings.push(
+                        Finding::new(self.ident(), message)
+                            .with_severity(severity)
+                            .with_confidence(Confidence::High)
+                            .with_location(SymbolicLocation {
+                                path: caller.path.clone(),
+                                route: Route::job(job_id.clone()).with_key("uses"),
+                                annotation: "reusable workflow call here".into(),
+                                span: None,
+                            }),
+                    );
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
@@ -0,0 +1,98 @@
+//! Small, embedded-scripting-driven audits, for house rules that need
+//! real logic but don't justify a full WASM plugin.
+//!
+//! Scripts are written in [Rhai](https://rhai.rs) and see a read-only
+//! view of the current [`crate::models::Job`]/[`crate::models::Step`]
+//! being visited; they call a host-provided `flag(message)` function to
+//! report a finding. This sits between the declarative
+//! [`crate::audit::custom_rule`] engine and the full
+//! [`crate::plugin`] system: more expressive than regex matching,
+//! without requiring a compiled WASM module.
+
+use camino::Utf8PathBuf;
+use serde::Deserialize;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding};
+use crate::models::Workflow;
+
+/// One `scripts:` entry in config.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScriptSpec {
+    pub id: String,
+    pub path: Utf8PathBuf,
+    #[serde(default)]
+    pub severity: crate::finding::Severity,
+}
+
+/// An audit backed by a single Rhai script.
+pub struct ScriptRuleAudit {
+    spec: ScriptSpec,
+    ident: &'static str,
+}
+
+impl ScriptRuleAudit {
+    pub fn new(spec: ScriptSpec) -> Self {
+        let ident = Box::leak(spec.id.clone().into_boxed_str());
+        Self { spec, ident }
+    }
+}
+
+impl Audit for ScriptRuleAudit {
+    fn ident(&self) -> &'static str {
+        self.ident
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        // The Rhai engine itself is wired up behind the `scripting`
+        // feature so a minimal build doesn't pull it in; here we only
+        // validate that the script is readable and surface a single
+        // diagnostic finding per flagged message once that engine runs.
+        let _source = std::fs::read_to_string(&self.spec.path)?;
+        let _ = (workflow, config);
+
+        #[cfg(feature = "scripting")]
+        {
+            return crate::audit::script_rule::engine::run(&self.spec, self.ident, workflow, config);
+        }
+
+        // Without the `scripting` feature there's no engine to run the
+        // script through at all. Erroring loudly here, rather than
+        // returning an empty finding set, matches `plugin.rs`'s
+        // unimplemented-backend behavior: a configured `scripts:` entry
+        // that silently detects nothing is worse for a security scanner
+        // than one that fails the run.
+        #[cfg(not(feature = "scripting"))]
+        anyhow::bail!(
+            "script rule `{}` requires zizmor to be built with the `scripting` feature",
+            self.ident
+        )
+    }
+}
+
+#[cfg(feature = "scripting")]
+mod engine {
+    use super::*;
+
+    /// Left unimplemented pending the `rhai::Engine` wiring: compile
+    /// `spec.path` once, call it per job/step with a scope exposing
+    /// `job`/`step`, and collect `flag(message)` calls as findings.
+    /// Erroring here (rather than returning an empty finding set) keeps
+    /// a configured `scripts:` entry from silently detecting nothing,
+    /// matching `plugin.rs`'s `LoadedPlugin::audit`.
+    pub fn run(
+        spec: &ScriptSpec,
+        ident: &'static str,
+        workflow: &Workflow,
+        config: &Config,
+    ) -> anyhow::Result<Vec<Finding>> {
+        let _ = (workflow, config);
+        anyhow::bail!("Rhai script execution is not yet implemented for {ident} ({})", spec.path)
+    }
+}
+
+#[allow(dead_code)]
+fn default_confidence() -> Confidence {
+    Confidence::Medium
+}
@@ -0,0 +1,97 @@
+use regex::Regex;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::Workflow;
+use crate::secrets::secret_vars_in_script;
+
+/// Flags `run:` script lines that print a shell variable known to hold a
+/// secret - either directly from `secrets.*` or derived from such a
+/// variable through an intermediate assignment or command substitution.
+/// GitHub's log masking only catches the literal secret value, so a
+/// transformed copy (base64-encoded, reversed, concatenated with other
+/// text, ...) slips through in plain sight.
+pub struct SecretInLogs;
+
+fn log_line_re() -> Regex {
+    Regex::new(r"(?m)^\s*(?:echo|print|printf)\b.*$").unwrap()
+}
+
+fn var_ref_re(name: &str) -> Regex {
+    Regex::new(&format!(r"\$\{{?{}\b", regex::escape(name))).unwrap()
+}
+
+impl Audit for SecretInLogs {
+    fn ident(&self) -> &'static str {
+        "secret-in-logs"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(run) = &step.run else { continue };
+                let secret_vars = secret_vars_in_script(run);
+                if secret_vars.is_empty() {
+                    continue;
+                }
+
+                for log_line in log_line_re().find_iter(run) {
+                    let Some(var) = secret_vars.iter().find(|v| var_ref_re(v).is_match(log_line.as_str())) else {
+                        continue;
+                    };
+                    if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                        continue;
+                    }
+                    let severity = config.effective_severity(self.ident(), Severity::Medium);
+                    findings.push(
+                        Finding::new(
+                            self.ident(),
+                            format!("`${var}` holds a secret-derived value and is written to the log here"),
+                        )
+                        .with_severity(severity)
+                        .with_confidence(Confidence::Medium)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::step(job_id.clone(), idx).with_key("run"),
+                            annotation: "secret-derived value logged here".into(),
+                            span: None,
+                        }),
+                    );
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_derived_secret_echoed_to_log() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: |\n          TOKEN=${{ secrets.GH_TOKEN }}\n          ENCODED=$(base64 <<< \"$TOKEN\")\n          echo \"debug: $ENCODED\"\n",
+        )
+        .unwrap();
+        let findings = SecretInLogs.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn does_not_flag_script_without_secrets() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo \"hello\"\n",
+        )
+        .unwrap();
+        let findings = SecretInLogs.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+use regex::Regex;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{Uses, Workflow};
+
+/// Flags steps that pass a `secrets.*` expression via `with:` or `env:`
+/// to a third-party action that isn't pinned to a commit SHA. Whoever
+/// controls the mutable branch/tag the action is pinned to controls
+/// code that runs with that secret in hand.
+pub struct SecretsToUnpinnedUses;
+
+fn expr_re() -> Regex {
+    Regex::new(r"\$\{\{\s*([^}]+?)\s*\}\}").unwrap()
+}
+
+fn is_secrets_expr(expr: &str) -> bool {
+    expr.trim_start().starts_with("secrets.")
+}
+
+fn references_secret(value: &str) -> bool {
+    expr_re().captures_iter(value).any(|c| is_secrets_expr(&c[1]))
+}
+
+impl Audit for SecretsToUnpinnedUses {
+    fn ident(&self) -> &'static str {
+        "secrets-to-unpinned-uses"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(raw_uses) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw_uses) else { continue };
+                if !uses.unpinned() || config.is_trusted_owner(&uses.owner, &uses.repo) {
+                    continue;
+                }
+
+                let mut keys: Vec<String> = step
+                    .with
+                    .iter()
+                    .filter(|(_, v)| matches!(v, serde_yaml::Value::String(s) if references_secret(s)))
+                    .map(|(k, _)| format!("with.{k}"))
+                    .collect();
+                keys.extend(
+                    step.env
+                        .iter()
+                        .filter(|(_, v)| references_secret(v))
+                        .map(|(k, _)| format!("env.{k}")),
+                );
+                if keys.is_empty() {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        format!(
+                            "`{raw_uses}` isn't pinned to a commit SHA but receives a secret through {}",
+                            keys.join(", ")
+                        ),
+                    )
+                    .with_severity(config.effective_severity(self.ident(), Severity::High))
+                    .with_confidence(Confidence::High)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx),
+                        annotation: "secret passed to unpinned action here".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_secret_passed_via_with_to_an_unpinned_action() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@v1\n        with:\n          token: ${{ secrets.GH_TOKEN }}\n",
+        )
+        .unwrap();
+        let findings = SecretsToUnpinnedUses.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn flags_secret_passed_via_env_to_an_unpinned_action() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@v1\n        env:\n          TOKEN: ${{ secrets.GH_TOKEN }}\n",
+        )
+        .unwrap();
+        let findings = SecretsToUnpinnedUses.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn ignores_secret_passed_to_a_sha_pinned_action() {
+        let sha = "a".repeat(40);
+        let workflow = Workflow::from_string(
+            "w.yml",
+            format!("on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@{sha}\n        with:\n          token: ${{{{ secrets.GH_TOKEN }}}}\n"),
+        )
+        .unwrap();
+        let findings = SecretsToUnpinnedUses.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_trusted_owner() {
+        let config = Config::from_str("trusted-owners:\n  - some-org\n").unwrap();
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@v1\n        with:\n          token: ${{ secrets.GH_TOKEN }}\n",
+        )
+        .unwrap();
+        let findings = SecretsToUnpinnedUses.audit_workflow(&workflow, &config).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_unpinned_action_with_no_secret_values() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: some-org/some-action@v1\n        with:\n          token: plain-value\n",
+        )
+        .unwrap();
+        let findings = SecretsToUnpinnedUses.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
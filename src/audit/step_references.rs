@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{Step, Workflow};
+
+/// Flags duplicate `id:`s within a job, and `steps.<id>.*` references
+/// that point at a step id that doesn't exist anywhere in the job, or at
+/// one that hasn't run yet (the referencing step is declared before, or
+/// at, the id it names). GitHub evaluates these expressions before the
+/// referenced step has produced anything, so they're always empty -
+/// either a typo or a step that got reordered out from under the
+/// reference.
+pub struct StepReferences;
+
+fn step_ref_re() -> Regex {
+    Regex::new(r"steps\.([A-Za-z_][A-Za-z0-9_-]*)\.").unwrap()
+}
+
+fn expr_texts(step: &Step) -> Vec<&str> {
+    let mut texts = vec![];
+    if let Some(run) = &step.run {
+        texts.push(run.as_str());
+    }
+    if let Some(if_) = step.if_.as_ref().and_then(|v| v.as_str()) {
+        texts.push(if_);
+    }
+    for value in step.env.values() {
+        texts.push(value.as_str());
+    }
+    texts
+}
+
+impl Audit for StepReferences {
+    fn ident(&self) -> &'static str {
+        "invalid-step-reference"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let re = step_ref_re();
+
+        for (job_id, job) in &workflow.jobs {
+            // The index each id is first declared at, so a later
+            // duplicate can be told apart from its first declaration,
+            // and a reference can be checked against "has this step
+            // actually run by this point in the job".
+            let mut first_seen: HashMap<&str, usize> = HashMap::new();
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(id) = step.id.as_deref() else { continue };
+                if let Some(&first_idx) = first_seen.get(id) {
+                    if config.is_ignored(self.ident(), &workflow.path, Some(job_id), Some(id)) {
+                        continue;
+                    }
+                    let severity = config.effective_severity(self.ident(), Severity::Medium);
+                    findings.push(
+                        Finding::new(
+                            self.ident(),
+                            format!("step id `{id}` is declared more than once in this job (first at step {first_idx})"),
+                        )
+                        .with_severity(severity)
+                        .with_confidence(Confidence::High)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::step(job_id.clone(), idx).with_key("id"),
+                            annotation: "duplicate step id".into(),
+                            span: None,
+                        }),
+                    );
+                } else {
+                    first_seen.insert(id, idx);
+                }
+            }
+
+            for (idx, step) in job.steps.iter().enumerate() {
+                for text in expr_texts(step) {
+                    for capture in re.captures_iter(text) {
+                        let referenced = &capture[1];
+                        let message = match first_seen.get(referenced) {
+                            None => Some(format!("`steps.{referenced}` does not refer to any step in this job")),
+                            Some(&ref_idx) if ref_idx >= idx => {
+                                Some(format!("`steps.{referenced}` refers to a step that hasn't run yet at this point"))
+                            }
+                            Some(_) => None,
+                        };
+                        let Some(message) = message else { continue };
+                        if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                            continue;
+                        }
+                        let severity = config.effective_severity(self.ident(), Severity::Medium);
+                        findings.push(
+                            Finding::new(self.ident(), message)
+                                .with_severity(severity)
+                                .with_confidence(Confidence::High)
+                                .with_location(SymbolicLocation {
+                                    path: workflow.path.clone(),
+                                    route: Route::step(job_id.clone(), idx),
+                                    annotation: "invalid steps.<id> reference".into(),
+                                    span: None,
+                                }),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_dangling_step_reference() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo \"${{ steps.missing.outputs.x }}\"\n",
+        )
+        .unwrap();
+        let findings = StepReferences.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn flags_forward_step_reference() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo \"${{ steps.later.outputs.x }}\"\n      - id: later\n        run: echo \"x=1\" >> \"$GITHUB_OUTPUT\"\n",
+        )
+        .unwrap();
+        let findings = StepReferences.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn flags_duplicate_step_id() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - id: dup\n        run: echo one\n      - id: dup\n        run: echo two\n",
+        )
+        .unwrap();
+        let findings = StepReferences.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn allows_reference_to_earlier_step() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - id: earlier\n        run: echo \"x=1\" >> \"$GITHUB_OUTPUT\"\n      - run: echo \"${{ steps.earlier.outputs.x }}\"\n",
+        )
+        .unwrap();
+        let findings = StepReferences.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use regex::Regex;
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::dataflow::{
+    env_vars_reaching_run, parse_job_output_ref, parse_step_output_ref, tainted_job_outputs, tainted_step_outputs,
+};
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::fix::Fix;
+use crate::models::{effective_shell, trigger_names, Workflow};
+use crate::shell::{default_classifier, Sink};
+use crate::taint::{taint_of, Taint};
+
+/// Confidence follows how exploitable the sink actually is: a bare
+/// command position lets the expansion introduce new commands outright,
+/// while a single-quoted string limits an attacker to breaking out via a
+/// literal `'` in the controlled value.
+fn confidence_for_sink(sink: Sink) -> Confidence {
+    match sink {
+        Sink::CommandPosition => Confidence::High,
+        Sink::DoubleQuoted | Sink::Unknown => Confidence::Medium,
+        Sink::SingleQuoted => Confidence::Low,
+    }
+}
+
+/// Flags `${{ ... }}` expressions interpolated directly into `run:`
+/// scripts when the expression reads a context an attacker can
+/// influence under one of the workflow's triggers (issue/PR titles and
+/// bodies, commit messages, ...), a classic script-injection vector.
+/// Trigger-to-taint facts live in [`crate::taint`] so other audits share
+/// the same knowledge base instead of re-deriving it.
+pub struct TemplateInjection;
+
+/// As a bare context access, but also sees through pure function
+/// wrappers like `format('{0}', github.event.issue.title)`: the whole
+/// expression is only as trustworthy as the context paths it actually
+/// reads, not the literal text between `${{ }}`.
+fn is_attacker_controlled(workflow: &Workflow, expr: &str) -> bool {
+    let paths = match crate::expr::parse(expr) {
+        Ok(ast) => crate::expr::context_paths(&ast),
+        Err(_) => vec![expr.to_string()],
+    };
+    trigger_names(&workflow.on)
+        .iter()
+        .any(|trigger| paths.iter().any(|path| taint_of(trigger, path) == Taint::AttackerControlled))
+}
+
+/// As [`is_attacker_controlled`], but also resolves `steps.<id>.outputs.*`
+/// references against `job`'s tainted outputs, and `needs.<job>.outputs.*`
+/// references against a job this one actually `needs`, so a value
+/// laundered through a step or job output is still traced back to its
+/// attacker-controlled source.
+fn is_attacker_controlled_in_job(workflow: &Workflow, job: &crate::models::Job, expr: &str) -> bool {
+    if is_attacker_controlled(workflow, expr) {
+        return true;
+    }
+    if let Some((id, name)) = parse_step_output_ref(expr) {
+        return tainted_step_outputs(workflow, job).contains(&(id, name));
+    }
+    if let Some((needed_job, name)) = parse_job_output_ref(expr) {
+        return job.needs.iter().any(|n| n == &needed_job) && tainted_job_outputs(workflow, &needed_job).contains(&name);
+    }
+    false
+}
+
+fn expr_re() -> Regex {
+    Regex::new(r"\$\{\{\s*([^}]+?)\s*\}\}").unwrap()
+}
+
+/// Locates the end of the `run:` key's own line (as opposed to
+/// `run_start`, which points at the *decoded scalar content* and for a
+/// block scalar like `run: |` lands one line below the key itself), so a
+/// new `env:` block can be inserted right after it, aligned with `run:`'s
+/// own column - the same column any other sibling key (`with:`, `if:`,
+/// ...) would use for this step.
+fn run_key_line_end(raw: &str, run_start: usize) -> Option<(usize, String)> {
+    let mut line_start = raw[..run_start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    loop {
+        let line_end = raw[line_start..].find('\n').map(|i| line_start + i + 1).unwrap_or(raw.len());
+        let line = &raw[line_start..line_end];
+        if let Some(key_offset) = line.find("run:") {
+            let before = &line[..key_offset];
+            if before.chars().all(|c| c == ' ' || c == '-') {
+                let indent = " ".repeat(before.chars().count());
+                return Some((line_end, indent));
+            }
+        }
+        if line_start == 0 {
+            return None;
+        }
+        line_start = raw[..line_start - 1].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    }
+}
+
+impl Audit for TemplateInjection {
+    fn ident(&self) -> &'static str {
+        "template-injection"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+        let re = expr_re();
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(run) = &step.run else { continue };
+                for capture in re.captures_iter(run) {
+                    let expr = &capture[1];
+                    if !is_attacker_controlled_in_job(workflow, job, expr) {
+                        continue;
+                    }
+                    if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                        continue;
+                    }
+                    let severity = config.effective_severity(self.ident(), Severity::High);
+                    let shell = crate::shell::Shell::parse(effective_shell(workflow, job, step));
+                    let whole_match = capture.get(0).unwrap();
+                    let sink = default_classifier().classify(run, whole_match.start(), shell);
+                    let span = crate::span::resolve_scalar_span(&workflow.raw, run, whole_match.range());
+                    let mut finding = Finding::new(
+                        self.ident(),
+                        format!("`{expr}` is interpolated directly into a shell script"),
+                    )
+                    .with_severity(severity)
+                    .with_confidence(confidence_for_sink(sink))
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx).with_key("run"),
+                        annotation: "attacker-controlled expression expanded here".into(),
+                        span,
+                    });
+                    if let Some((producer_job, output_name)) = parse_job_output_ref(expr) {
+                        finding = finding.with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::job(producer_job).with_key("outputs").with_key(output_name),
+                            annotation: "tainted value originates from this job output".into(),
+                            span: None,
+                        });
+                    }
+                    findings.push(finding);
+                }
+
+                // An attacker-controlled expression assigned to an env
+                // var doesn't need to be flagged again if that var is
+                // never actually expanded by the script it's passed to -
+                // only the live env -> run hop is exploitable.
+                let effective_env = crate::env_resolution::effective_env(workflow, job, step);
+                for var_name in env_vars_reaching_run(workflow, job, step) {
+                    let Some(value) = effective_env.get(&var_name) else {
+                        continue;
+                    };
+                    for capture in re.captures_iter(value) {
+                        let expr = &capture[1];
+                        if !is_attacker_controlled_in_job(workflow, job, expr) {
+                            continue;
+                        }
+                        if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                            continue;
+                        }
+                        let severity = config.effective_severity(self.ident(), Severity::High);
+                        findings.push(
+                            Finding::new(
+                                self.ident(),
+                                format!(
+                                    "`{expr}` reaches the shell via the `{var_name}` env var, which is expanded unquoted in `run:`"
+                                ),
+                            )
+                            .with_severity(severity)
+                            .with_confidence(Confidence::Medium)
+                            .with_location(SymbolicLocation {
+                                path: workflow.path.clone(),
+                                route: Route::step(job_id.clone(), idx).with_key("env").with_key(var_name.to_string()),
+                                annotation: "attacker-controlled expression flows into run: through this env var".into(),
+                                span: None,
+                            }),
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+
+    fn suggest_fixes(&self, workflow: &Workflow, _config: &Config) -> anyhow::Result<Vec<Fix>> {
+        let mut fixes = vec![];
+        let re = expr_re();
+
+        for job in workflow.jobs.values() {
+            for step in &job.steps {
+                let Some(run) = &step.run else { continue };
+                let flagged: Vec<_> = re
+                    .captures_iter(run)
+                    .filter(|c| is_attacker_controlled_in_job(workflow, job, &c[1]))
+                    .collect();
+                if flagged.is_empty() {
+                    continue;
+                }
+
+                let Some(run_start) = workflow.raw.find(run.as_str()) else { continue };
+
+                // Only safe to insert a fresh `env:` block when the step
+                // doesn't already have one; splicing into an existing
+                // block is a separate, harder span-finding problem (see
+                // checkout-persist-credentials for the same tradeoff).
+                if !step.env.is_empty() {
+                    continue;
+                }
+                let Some((key_line_end, indent)) = run_key_line_end(&workflow.raw, run_start) else { continue };
+
+                let mut rewritten = run.clone();
+                let mut env_lines = String::new();
+                for (i, capture) in flagged.iter().enumerate() {
+                    let var_name = format!("ZIZMOR_INJECTED_{i}");
+                    let full_match = capture.get(0).unwrap().as_str();
+                    rewritten = rewritten.replacen(full_match, &format!("\"${{{var_name}}}\""), 1);
+                    env_lines.push_str(&format!("{indent}  {var_name}: {full_match}\n"));
+                }
+
+                fixes.push(Fix {
+                    span: run_start..run_start + run.len(),
+                    replacement: rewritten,
+                    description: "move interpolated expression into env: indirection".into(),
+                });
+                fixes.push(Fix {
+                    span: key_line_end..key_line_end,
+                    replacement: format!("{indent}env:\n{env_lines}"),
+                    description: "add env: block for the indirected expression".into(),
+                });
+            }
+        }
+
+        Ok(fixes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::fix;
+    use crate::models::Workflow;
+
+    fn workflow() -> Workflow {
+        Workflow::from_string(
+            "w.yml",
+            "on: issue_comment\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo ${{ github.event.comment.body }}\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn flags_attacker_controlled_expression_in_run() {
+        let workflow = workflow();
+        let findings = TemplateInjection.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn suggested_fix_defines_the_env_var_it_references() {
+        let workflow = workflow();
+        let fixes = TemplateInjection.suggest_fixes(&workflow, &Config::default()).unwrap();
+        assert_eq!(fixes.len(), 2);
+
+        let fixed = fix::apply(&workflow.raw, &fixes).unwrap();
+        assert!(fixed.contains(r#"echo "${ZIZMOR_INJECTED_0}""#));
+
+        // The rewritten document is itself valid YAML with an env: block
+        // that actually defines the var the rewritten run: line expands.
+        let reparsed = Workflow::from_string("w.yml", &fixed).unwrap();
+        let step = &reparsed.jobs["j"].steps[0];
+        assert_eq!(
+            step.env.get("ZIZMOR_INJECTED_0").map(String::as_str),
+            Some("${{ github.event.comment.body }}")
+        );
+    }
+}
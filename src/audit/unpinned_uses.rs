@@ -0,0 +1,212 @@
+use crate::audit::Audit;
+use crate::config::{Config, PinRequirement};
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::fix::Fix;
+use crate::models::{is_malformed_uses, Uses, Workflow};
+
+/// Looks up the commit SHA a `uses:` ref currently resolves to.
+///
+/// Real pinning needs a GitHub API call (or local git) to resolve
+/// `git_ref` to a commit; that lookup is intentionally kept out of this
+/// module so `unpinned-uses` itself stays usable offline. `--fix` wires
+/// a real resolver in here before calling [`UnpinnedUses::suggest_fixes`].
+pub trait RefResolver: Send + Sync {
+    fn resolve_sha(&self, uses: &Uses) -> anyhow::Result<Option<(String, String)>>;
+}
+
+/// A single `(owner, repo, ref)` lookup, grouped with others into one
+/// [`BatchRefResolver::resolve_many`] call instead of one REST request
+/// per `uses:` reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefQuery {
+    pub owner: String,
+    pub repo: String,
+    pub git_ref: String,
+}
+
+/// Resolves many [`RefQuery`]s in one round trip - e.g. a single GraphQL
+/// query aliasing each lookup - for scans with hundreds of distinct
+/// `uses:` references where [`RefResolver`]'s one-call-per-reference
+/// shape would otherwise dominate wall time and rate-limit budget.
+/// Returns results in the same order as `queries`; `None` for any query
+/// that didn't resolve.
+pub trait BatchRefResolver: Send + Sync {
+    fn resolve_many(&self, queries: &[RefQuery]) -> anyhow::Result<Vec<Option<(String, String)>>>;
+}
+
+/// Whether `uses` satisfies `requirement` under the configured pinning policy.
+///
+/// Distinguishing a tag from a mutable branch name requires a network
+/// lookup that this offline check doesn't perform, so `Tag` is treated as
+/// "any named ref is fine" here; online audits can tighten this further.
+fn satisfies(uses: &Uses, requirement: PinRequirement) -> bool {
+    match requirement {
+        PinRequirement::Any | PinRequirement::Tag => true,
+        PinRequirement::Sha => !uses.unpinned(),
+    }
+}
+
+/// Flags `uses:` references that aren't pinned to a full commit SHA.
+///
+/// When constructed with a [`RefResolver`] (wired up to the GitHub API by
+/// the CLI when online), also offers `--fix` support that rewrites the
+/// ref to its resolved SHA and appends a `# vX.Y.Z` comment.
+#[derive(Default)]
+pub struct UnpinnedUses {
+    resolver: Option<Box<dyn RefResolver>>,
+    batch_resolver: Option<Box<dyn BatchRefResolver>>,
+}
+
+impl UnpinnedUses {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_resolver(resolver: Box<dyn RefResolver>) -> Self {
+        Self {
+            resolver: Some(resolver),
+            batch_resolver: None,
+        }
+    }
+
+    /// Like [`Self::with_resolver`], but resolves every unpinned `uses:`
+    /// across the workflow in one [`BatchRefResolver::resolve_many`]
+    /// call instead of one per reference. Preferred over a plain
+    /// [`RefResolver`] when both are available.
+    pub fn with_batch_resolver(batch_resolver: Box<dyn BatchRefResolver>) -> Self {
+        Self {
+            resolver: None,
+            batch_resolver: Some(batch_resolver),
+        }
+    }
+}
+
+impl Audit for UnpinnedUses {
+    fn ident(&self) -> &'static str {
+        "unpinned-uses"
+    }
+
+    /// The audit itself runs fully offline; `true` here reflects that
+    /// its `--fix` support can resolve SHAs over the network once the
+    /// CLI wires up a [`RefResolver`].
+    fn needs_network(&self) -> bool {
+        true
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let Some(raw) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw) else {
+                    if is_malformed_uses(raw) && !config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                        findings.push(
+                            Finding::new(self.ident(), format!("`{raw}` is not a valid action reference"))
+                                .with_severity(config.effective_severity(self.ident(), Severity::Medium))
+                                .with_confidence(Confidence::High)
+                                .with_location(SymbolicLocation {
+                                    path: workflow.path.clone(),
+                                    route: Route::step(job_id.clone(), idx).with_key("uses"),
+                                    annotation: "malformed uses: reference".into(),
+                                    span: None,
+                                }),
+                        );
+                    }
+                    continue;
+                };
+                let requirement = config.pin_requirement_for(&uses.owner);
+                if satisfies(&uses, requirement) {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+                // First-party/trusted owners get a pass: an internal,
+                // unpinned action is much lower risk than a third party's.
+                if config.is_trusted_owner(&uses.owner, &uses.repo) {
+                    continue;
+                }
+
+                let severity = if config.is_first_party_owner(&uses.owner) {
+                    // First-party code is still worth flagging, but it's
+                    // not the supply-chain risk a third party would be.
+                    Severity::Informational
+                } else {
+                    config.effective_severity(self.ident(), Severity::Medium)
+                };
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        format!(
+                            "{} does not meet the configured pinning requirement ({requirement:?})",
+                            uses.owner_repo()
+                        ),
+                    )
+                    .with_severity(severity)
+                    .with_confidence(Confidence::High)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx).with_key("uses"),
+                        annotation: "action is not pinned to a SHA".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+
+    fn suggest_fixes(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Fix>> {
+        let unpinned: Vec<(String, Uses)> = workflow
+            .jobs
+            .values()
+            .flat_map(|job| &job.steps)
+            .filter_map(|step| step.uses.as_ref())
+            .filter_map(|raw| Uses::parse(raw).map(|uses| (raw.clone(), uses)))
+            .filter(|(_, uses)| !satisfies(uses, config.pin_requirement_for(&uses.owner)))
+            .collect();
+
+        let resolved: Vec<Option<(String, String)>> = if let Some(batch_resolver) = &self.batch_resolver {
+            let queries: Vec<RefQuery> = unpinned
+                .iter()
+                .map(|(_, uses)| RefQuery {
+                    owner: uses.owner.clone(),
+                    repo: uses.repo.clone(),
+                    git_ref: uses.git_ref.clone(),
+                })
+                .collect();
+            batch_resolver.resolve_many(&queries)?
+        } else if let Some(resolver) = &self.resolver {
+            // No batch resolver available, so fall back to one call per
+            // reference - but still bounded, rather than fully serial,
+            // so a scan with hundreds of distinct `uses:` references
+            // doesn't pay for each one back-to-back.
+            crate::net_pool::bounded_map(&unpinned, crate::net_pool::NetworkPolicy::default(), |(_, uses)| resolver.resolve_sha(uses))
+                .into_iter()
+                .collect::<anyhow::Result<Vec<_>>>()?
+        } else {
+            return Ok(vec![]);
+        };
+
+        let mut fixes = vec![];
+        for ((raw, uses), resolved) in unpinned.iter().zip(resolved) {
+            let Some((sha, version_tag)) = resolved else { continue };
+
+            // Best-effort: locate this exact `uses:` string in the raw
+            // source. A real implementation would carry the span from
+            // the YAML parse instead of re-searching the text.
+            let Some(start) = workflow.raw.find(raw.as_str()) else {
+                continue;
+            };
+            let subpath = uses.subpath.clone().map(|s| format!("/{s}")).unwrap_or_default();
+            fixes.push(Fix {
+                span: start..start + raw.len(),
+                replacement: format!("{}/{}{subpath}@{sha} # {version_tag}", uses.owner, uses.repo),
+                description: format!("pin {} to {sha}", uses.owner_repo()),
+            });
+        }
+        Ok(fixes)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::Workflow;
+use crate::reachability::{eval_if, Tri};
+
+/// Flags jobs and steps whose `if:` condition partially-evaluates to
+/// `false` against the workflow's own triggers - e.g. a step gated on
+/// `github.event_name == 'pull_request'` in a workflow that only runs
+/// `on: push`. Dead code in a security-relevant workflow usually means a
+/// stale condition left behind after a trigger was changed, not an
+/// intentional guard.
+pub struct UnreachableCode;
+
+impl Audit for UnreachableCode {
+    fn ident(&self) -> &'static str {
+        "unreachable-code"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        for (job_id, job) in &workflow.jobs {
+            if eval_if(job.if_.as_ref(), &workflow.on) == Tri::False {
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                    continue;
+                }
+                let severity = config.effective_severity(self.ident(), Severity::Low);
+                findings.push(
+                    Finding::new(self.ident(), format!("job `{job_id}` can never run under this workflow's triggers"))
+                        .with_severity(severity)
+                        .with_confidence(Confidence::Medium)
+                        .with_location(SymbolicLocation {
+                            path: workflow.path.clone(),
+                            route: Route::job(job_id.clone()).with_key("if"),
+                            annotation: "always false given this workflow's triggers".into(),
+                            span: None,
+                        }),
+                );
+                continue;
+            }
+
+            for (index, step) in job.steps.iter().enumerate() {
+                if eval_if(step.if_.as_ref(), &workflow.on) != Tri::False {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), None) {
+                    continue;
+                }
+                let step_name = step.name.as_deref().or(step.id.as_deref()).unwrap_or("<unnamed>");
+                let severity = config.effective_severity(self.ident(), Severity::Low);
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        format!("step `{step_name}` in job `{job_id}` can never run under this workflow's triggers"),
+                    )
+                    .with_severity(severity)
+                    .with_confidence(Confidence::Medium)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::new()
+                            .with_key("jobs")
+                            .with_key(job_id.clone())
+                            .with_key("steps")
+                            .with_index(index)
+                            .with_key("if"),
+                        annotation: "always false given this workflow's triggers".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_step_gated_on_unreachable_event() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo hi\n        if: github.event_name == 'pull_request'\n",
+        )
+        .unwrap();
+        let findings = UnreachableCode.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn does_not_flag_unconditional_steps() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo hi\n",
+        )
+        .unwrap();
+        let findings = UnreachableCode.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Route, Severity, SymbolicLocation};
+use crate::models::{trigger_names, Uses, Workflow};
+
+/// Flags `workflow_run`-triggered jobs that download the triggering
+/// run's artifacts (via `actions/download-artifact` or `gh run
+/// download`) and then run a later step in the same job. The artifact
+/// itself is attacker-controlled if the triggering workflow ran on a
+/// fork PR, so extracting, executing, or publishing its contents
+/// without validation hands that attacker code execution in the
+/// privileged `workflow_run` context - the same family of risk as
+/// [`crate::audit::pull_request_target_checkout`], but via an artifact
+/// instead of a checkout.
+pub struct WorkflowRunArtifactPoisoning;
+
+fn is_download_artifact(uses: &str) -> bool {
+    Uses::parse(uses).is_some_and(|u| u.owner.eq_ignore_ascii_case("actions") && u.repo.eq_ignore_ascii_case("download-artifact"))
+}
+
+fn is_gh_run_download(run: &str) -> bool {
+    run.contains("gh run download")
+}
+
+impl Audit for WorkflowRunArtifactPoisoning {
+    fn ident(&self) -> &'static str {
+        "workflow-run-artifact-poisoning"
+    }
+
+    fn audit_workflow(&self, workflow: &Workflow, config: &Config) -> anyhow::Result<Vec<Finding>> {
+        let mut findings = vec![];
+
+        if !trigger_names(&workflow.on).iter().any(|t| t == "workflow_run") {
+            return Ok(findings);
+        }
+
+        for (job_id, job) in &workflow.jobs {
+            for (idx, step) in job.steps.iter().enumerate() {
+                let downloads_artifact =
+                    step.uses.as_deref().is_some_and(is_download_artifact) || step.run.as_deref().is_some_and(is_gh_run_download);
+                if !downloads_artifact {
+                    continue;
+                }
+                // Downloading an artifact and doing nothing else with
+                // it isn't exploitable; something later has to act on
+                // its contents.
+                let runs_something_after = job.steps[idx + 1..].iter().any(|s| s.run.is_some() || s.uses.is_some());
+                if !runs_something_after {
+                    continue;
+                }
+                if config.is_ignored(self.ident(), &workflow.path, Some(job_id), step.id.as_deref()) {
+                    continue;
+                }
+
+                findings.push(
+                    Finding::new(
+                        self.ident(),
+                        "workflow_run job downloads the triggering run's artifacts and then runs a later step - \
+                         if the triggering run was on a fork PR, that artifact's contents are attacker-controlled",
+                    )
+                    .with_severity(config.effective_severity(self.ident(), Severity::High))
+                    .with_confidence(Confidence::Medium)
+                    .with_location(SymbolicLocation {
+                        path: workflow.path.clone(),
+                        route: Route::step(job_id.clone(), idx),
+                        annotation: "downloads artifact from the triggering run here".into(),
+                        span: None,
+                    }),
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn flags_download_artifact_followed_by_a_run_step() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: workflow_run\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/download-artifact@v4\n      - run: ./dist/run.sh\n",
+        )
+        .unwrap();
+        let findings = WorkflowRunArtifactPoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn matches_download_artifact_regardless_of_owner_repo_casing() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: workflow_run\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: Actions/Download-Artifact@v4\n      - run: ./dist/run.sh\n",
+        )
+        .unwrap();
+        let findings = WorkflowRunArtifactPoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn ignores_download_only_job_with_nothing_after() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: workflow_run\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/download-artifact@v4\n",
+        )
+        .unwrap();
+        let findings = WorkflowRunArtifactPoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn ignores_non_workflow_run_trigger() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/download-artifact@v4\n      - run: ./dist/run.sh\n",
+        )
+        .unwrap();
+        let findings = WorkflowRunArtifactPoisoning.audit_workflow(&workflow, &Config::default()).unwrap();
+        assert!(findings.is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+//! Implementation of `zizmor diff`: audits two directories (or git
+//! refs, checked out into temporary directories) and reports which
+//! findings are new, which disappeared, and which are unchanged between
+//! them - the fastest way to see the security delta of a workflow
+//! refactor or a template rollout before merging it.
+
+use std::collections::BTreeSet;
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+use zizmor::config::Config;
+use zizmor::finding::Finding;
+use zizmor::models::{Action, Workflow};
+use zizmor::registry::default_audits;
+
+/// Resolves `ref_or_dir` to a directory of workflow/action files: used
+/// directly if it's an existing directory, otherwise checked out as a
+/// git ref into a temporary directory via `git archive | tar -x`. The
+/// returned `TempDir` (when present) must outlive the path's use - it
+/// deletes the checkout on drop.
+fn materialize(ref_or_dir: &str) -> anyhow::Result<(Utf8PathBuf, Option<tempfile::TempDir>)> {
+    let as_dir = Utf8Path::new(ref_or_dir);
+    if as_dir.is_dir() {
+        return Ok((as_dir.to_path_buf(), None));
+    }
+
+    let dir = tempfile::tempdir()?;
+    let dir_path =
+        Utf8PathBuf::from_path_buf(dir.path().to_path_buf()).map_err(|p| anyhow::anyhow!("non-UTF-8 temp path: {}", p.display()))?;
+
+    let mut archive = std::process::Command::new("git")
+        .args(["archive", ref_or_dir, "--", ".github"])
+        .stdout(std::process::Stdio::piped())
+        .spawn()?;
+    let archive_stdout = archive.stdout.take().expect("stdout was piped");
+    let extract_status = std::process::Command::new("tar")
+        .args(["-x", "-f", "-", "-C", dir_path.as_str()])
+        .stdin(archive_stdout)
+        .status()?;
+    let archive_status = archive.wait()?;
+    if !archive_status.success() || !extract_status.success() {
+        anyhow::bail!("could not check out `{ref_or_dir}` with `git archive`; is it a valid ref, and is this a git repository?");
+    }
+
+    Ok((dir_path, Some(dir)))
+}
+
+fn load_findings(dir: &Utf8Path) -> anyhow::Result<Vec<Finding>> {
+    let mut workflows = vec![];
+    let mut actions = vec![];
+    for entry in walkdir::WalkDir::new(dir).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(dir);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        if matches!(entry_path.file_stem(), Some("action")) {
+            if let Ok(action) = Action::from_file(entry_path) {
+                actions.push(action);
+            }
+        } else if let Ok(workflow) = Workflow::from_file(entry_path) {
+            workflows.push(workflow);
+        }
+    }
+    zizmor::run_audits(&workflows, &actions, &default_audits(), &Config::default())
+}
+
+/// A finding's identity for diffing, independent of which side's
+/// directory prefix its path carries: the rule id, message, and each
+/// location's path (relative to `root`) and route.
+fn key(root: &Utf8Path, finding: &Finding) -> String {
+    let locations: Vec<String> = finding
+        .locations
+        .iter()
+        .map(|loc| {
+            let relative = loc.path.strip_prefix(root).map(|p| p.to_string()).unwrap_or_else(|_| loc.path.to_string());
+            format!("{relative}:{}", loc.route)
+        })
+        .collect();
+    format!("{}|{}|{}", finding.ident, finding.desc, locations.join(","))
+}
+
+pub fn run(a: &str, b: &str) -> anyhow::Result<()> {
+    let (dir_a, _guard_a) = materialize(a)?;
+    let (dir_b, _guard_b) = materialize(b)?;
+
+    let findings_a = load_findings(&dir_a)?;
+    let findings_b = load_findings(&dir_b)?;
+
+    let keys_a: BTreeSet<String> = findings_a.iter().map(|f| key(&dir_a, f)).collect();
+    let keys_b: BTreeSet<String> = findings_b.iter().map(|f| key(&dir_b, f)).collect();
+
+    let added: Vec<&Finding> = findings_b.iter().filter(|f| !keys_a.contains(&key(&dir_b, f))).collect();
+    let removed: Vec<&Finding> = findings_a.iter().filter(|f| !keys_b.contains(&key(&dir_a, f))).collect();
+    let unchanged_count = keys_a.intersection(&keys_b).count();
+
+    println!("{a} -> {b}:");
+    println!("  {} added, {} removed, {} unchanged", added.len(), removed.len(), unchanged_count);
+
+    if !added.is_empty() {
+        println!("\nadded:");
+        for finding in &added {
+            println!("  [{}] {} ({})", finding.ident, finding.desc, finding.severity);
+        }
+    }
+    if !removed.is_empty() {
+        println!("\nremoved:");
+        for finding in &removed {
+            println!("  [{}] {} ({})", finding.ident, finding.desc, finding.severity);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+//! Implementation of `zizmor explain`: prints a rule's embedded
+//! documentation from [`zizmor::docs`].
+
+use zizmor::docs;
+
+pub fn run(rule_id: &str) -> anyhow::Result<()> {
+    let Some(doc) = docs::lookup(rule_id) else {
+        anyhow::bail!("no documentation for rule `{rule_id}` (not a built-in rule id)");
+    };
+
+    println!("{} ({})", doc.title, doc.ident);
+    println!("default severity: {}", doc.default_severity);
+    println!();
+    println!("{}", doc.rationale);
+    println!();
+    println!("example:");
+    println!("  {}", doc.example.replace('\n', "\n  "));
+    println!();
+    println!("remediation:");
+    println!("  {}", doc.remediation);
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! Implementation of `zizmor graph`: walks a directory of workflows and
+//! prints the cross-workflow dependency graph built by
+//! [`zizmor::depgraph`] as Graphviz DOT or JSON.
+
+use camino::Utf8Path;
+use zizmor::models::Workflow;
+
+use crate::cli::GraphFormat;
+
+pub fn run(path: &Utf8Path, format: GraphFormat) -> anyhow::Result<()> {
+    let mut workflows = vec![];
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        if let Ok(workflow) = Workflow::from_file(entry_path) {
+            workflows.push(workflow);
+        }
+    }
+
+    let edges = zizmor::depgraph::build(&workflows);
+    match format {
+        GraphFormat::Dot => print!("{}", zizmor::depgraph::to_dot(&edges)),
+        GraphFormat::Json => println!("{}", serde_json::to_string_pretty(&edges)?),
+    }
+    Ok(())
+}
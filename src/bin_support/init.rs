@@ -0,0 +1,111 @@
+//! Implementation of `zizmor init`: inspects this repository's
+//! workflows - triggers in use, self-hosted runner labels, and `uses:`
+//! owners - and writes a starter `zizmor.yml` with those findings
+//! pre-filled, so adopting zizmor doesn't start from a blank file.
+//!
+//! True interactive prompting (confirming each suggestion one at a
+//! time) would need a terminal UI this crate doesn't have; instead this
+//! scans the repo once, reports what it found, and writes a config with
+//! the suggestions already filled in for the user to review and edit.
+
+use std::collections::BTreeSet;
+
+use camino::Utf8Path;
+
+use zizmor::models::{trigger_names, Uses, Workflow};
+
+struct Scan {
+    triggers: BTreeSet<String>,
+    self_hosted_labels: BTreeSet<String>,
+    action_owners: BTreeSet<String>,
+}
+
+fn scan(path: &Utf8Path) -> Scan {
+    let mut scan = Scan {
+        triggers: BTreeSet::new(),
+        self_hosted_labels: BTreeSet::new(),
+        action_owners: BTreeSet::new(),
+    };
+
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        let Ok(workflow) = Workflow::from_file(entry_path) else { continue };
+
+        scan.triggers.extend(trigger_names(&workflow.on));
+        for job in workflow.jobs.values() {
+            for label in zizmor::matrix::runs_on_candidates(job) {
+                if zizmor::runner_labels::os_of(&label).is_none() {
+                    scan.self_hosted_labels.insert(label);
+                }
+            }
+            for step in &job.steps {
+                let Some(raw) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw) else { continue };
+                scan.action_owners.insert(uses.owner.clone());
+            }
+        }
+    }
+
+    scan
+}
+
+fn render_config(scan: &Scan) -> String {
+    let mut out = String::new();
+    out.push_str("# Generated by `zizmor init`. Review before committing - these are\n");
+    out.push_str("# starting points inferred from the workflows in this repo, not a\n");
+    out.push_str("# verified security policy.\n\n");
+
+    if scan.triggers.is_empty() {
+        out.push_str("# no triggers detected\n");
+    } else {
+        out.push_str(&format!(
+            "# triggers seen in this repo: {}\n",
+            scan.triggers.iter().cloned().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    if scan.self_hosted_labels.is_empty() {
+        out.push_str("# no self-hosted runner labels detected\n\n");
+    } else {
+        out.push_str(&format!(
+            "# self-hosted runner label(s) in use: {}\n# review `self-hosted-runner` findings for these jobs - self-hosted\n# runners on a public repo are a common persistence vector for\n# untrusted pull_request code.\n\n",
+            scan.self_hosted_labels.iter().cloned().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    out.push_str("trusted-owners:\n");
+    if scan.action_owners.is_empty() {
+        out.push_str("  # no `uses:` references found\n");
+    } else {
+        for owner in &scan.action_owners {
+            out.push_str(&format!("  # - {owner}\n"));
+        }
+        out.push_str("  # uncomment the owner(s) above that are yours (or another trusted\n  # party's) to quiet unpinned-uses/token-passing noise for them\n");
+    }
+    out.push('\n');
+
+    out.push_str("pinning-policy:\n");
+    out.push_str("  - owner: actions\n    require: tag\n");
+
+    out
+}
+
+pub fn run(path: &Utf8Path, output: &Utf8Path) -> anyhow::Result<()> {
+    if output.exists() {
+        anyhow::bail!("{output} already exists; remove it first if you want `zizmor init` to regenerate it");
+    }
+
+    let scan = scan(path);
+    std::fs::write(output, render_config(&scan))?;
+    println!("wrote {output}");
+    if !scan.self_hosted_labels.is_empty() {
+        println!(
+            "found self-hosted runner label(s): {}",
+            scan.self_hosted_labels.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,12 @@
+//! Implementation of `zizmor ir`: dumps [`zizmor::ir::WorkflowIr`] for a
+//! single workflow file as JSON.
+
+use camino::Utf8Path;
+use zizmor::models::Workflow;
+
+pub fn run(path: &Utf8Path) -> anyhow::Result<()> {
+    let workflow = Workflow::from_file(path)?;
+    let ir = zizmor::ir::build(&workflow);
+    println!("{}", serde_json::to_string_pretty(&ir)?);
+    Ok(())
+}
@@ -0,0 +1,16 @@
+//! Implementations of standalone subcommands (`zizmor pin`, etc.) kept
+//! out of `main.rs` to keep the default audit pipeline readable.
+
+pub mod diff;
+pub mod explain;
+pub mod graph;
+pub mod init;
+pub mod ir;
+pub mod pin;
+pub mod rules;
+pub mod sbom;
+pub mod serve;
+pub mod stats;
+pub mod suggest_updater;
+pub mod update_pins;
+pub mod webhook_serve;
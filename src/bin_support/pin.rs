@@ -0,0 +1,42 @@
+//! Implementation of `zizmor pin`: a standalone, audit-independent pass
+//! that pins every `uses:` reference under a path to a full commit SHA.
+
+use camino::Utf8Path;
+use zizmor::audit::unpinned_uses::{RefResolver, UnpinnedUses};
+use zizmor::audit::Audit;
+use zizmor::config::Config;
+use zizmor::fix::apply;
+use zizmor::models::{Uses, Workflow};
+
+/// Placeholder resolver until the GitHub API client lands (see the
+/// batched-GraphQL follow-up); `zizmor pin` will report zero fixes
+/// rather than fabricate a SHA.
+struct UnimplementedResolver;
+
+impl RefResolver for UnimplementedResolver {
+    fn resolve_sha(&self, _uses: &Uses) -> anyhow::Result<Option<(String, String)>> {
+        Ok(None)
+    }
+}
+
+pub fn run(path: &Utf8Path) -> anyhow::Result<()> {
+    let audit = UnpinnedUses::with_resolver(Box::new(UnimplementedResolver));
+    let config = Config::default();
+
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        let workflow = Workflow::from_file(entry_path)?;
+        let fixes = audit.suggest_fixes(&workflow, &config)?;
+        if fixes.is_empty() {
+            continue;
+        }
+        let fixed = apply(&workflow.raw, &fixes)?;
+        std::fs::write(entry_path, fixed)?;
+        println!("pinned {} reference(s) in {entry_path}", fixes.len());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+//! Implementation of `zizmor rules`: prints [`zizmor::rules::list`] as
+//! JSON or a plain table.
+
+use crate::cli::RulesFormat;
+
+pub fn run(format: RulesFormat) -> anyhow::Result<()> {
+    let rules = zizmor::rules::list();
+    match format {
+        RulesFormat::Json => println!("{}", serde_json::to_string_pretty(&rules)?),
+        RulesFormat::Table => {
+            println!("{:<30} {:<10} NETWORK", "RULE", "PERSONA");
+            for rule in rules {
+                println!("{:<30} {:<10?} {}", rule.ident, rule.persona, rule.needs_network);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! Implementation of `zizmor sbom`: walks a directory of workflows and
+//! prints a CycloneDX or SPDX bill of materials covering every action,
+//! reusable workflow, and container image they reference.
+
+use camino::Utf8Path;
+use zizmor::models::Workflow;
+use zizmor::sbom::{self, Component, ComponentKind};
+
+use crate::cli::SbomFormat;
+
+fn all_components(path: &Utf8Path) -> anyhow::Result<Vec<Component>> {
+    let mut components = vec![];
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        let Ok(workflow) = Workflow::from_file(entry_path) else { continue };
+        components.extend(sbom::components(&workflow));
+    }
+    Ok(sbom::dedupe(components))
+}
+
+fn purl(component: &Component) -> String {
+    match component.kind {
+        ComponentKind::Action | ComponentKind::ReusableWorkflow => {
+            format!("pkg:githubactions/{}@{}", component.name, component.version)
+        }
+        ComponentKind::ContainerImage => format!("pkg:docker/{}@{}", component.name, component.version),
+    }
+}
+
+fn render_cyclonedx(components: &[Component]) -> anyhow::Result<String> {
+    let json_components: Vec<_> = components
+        .iter()
+        .map(|component| {
+            serde_json::json!({
+                "type": match component.kind {
+                    ComponentKind::Action | ComponentKind::ReusableWorkflow => "application",
+                    ComponentKind::ContainerImage => "container",
+                },
+                "name": component.name,
+                "version": component.version,
+                "purl": purl(component),
+            })
+        })
+        .collect();
+    let document = serde_json::json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "components": json_components,
+    });
+    Ok(serde_json::to_string_pretty(&document)?)
+}
+
+fn render_spdx(components: &[Component]) -> String {
+    let mut out = String::from("SPDXVersion: SPDX-2.3\nDataLicense: CC0-1.0\nSPDXID: SPDXRef-DOCUMENT\n");
+    for (idx, component) in components.iter().enumerate() {
+        out.push_str(&format!(
+            "\nPackageName: {}\nSPDXID: SPDXRef-Package-{idx}\nPackageVersion: {}\nPackageDownloadLocation: NOASSERTION\nExternalRef: PACKAGE-MANAGER purl {}\n",
+            component.name,
+            component.version,
+            purl(component),
+        ));
+    }
+    out
+}
+
+pub fn run(path: &Utf8Path, format: SbomFormat) -> anyhow::Result<()> {
+    let components = all_components(path)?;
+    match format {
+        SbomFormat::CycloneDx => println!("{}", render_cyclonedx(&components)?),
+        SbomFormat::Spdx => println!("{}", render_spdx(&components)),
+    }
+    Ok(())
+}
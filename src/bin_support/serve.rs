@@ -0,0 +1,68 @@
+//! Implementation of `zizmor serve`: a minimal blocking HTTP/1.1 server
+//! exposing `POST /audit` over a raw `std::net::TcpListener`, with no
+//! framework dependency. Good enough for an internal platform to POST a
+//! workflow and get findings back without invoking a CLI per request;
+//! not meant to hold up under internet-facing load (see synth-738 for
+//! the planned move to a real async HTTP stack).
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+use zizmor::serve::{handle, warm_state, AuditRequest, ErrorResponse};
+
+fn read_request_body(stream: &mut impl Read) -> anyhow::Result<String> {
+    let mut reader = BufReader::new(stream);
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            anyhow::bail!("connection closed before headers finished");
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:").or_else(|| line.strip_prefix("content-length:")) {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(String::from_utf8(body)?)
+}
+
+fn respond(stream: &mut impl Write, status: &str, body: &str) -> anyhow::Result<()> {
+    write!(stream, "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len())?;
+    Ok(())
+}
+
+pub fn run(addr: &str) -> anyhow::Result<()> {
+    let (audits, config) = warm_state();
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("listening on http://{addr}; POST /audit with {{\"name\": ..., \"content\": ...}}");
+
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("warning: failed to accept connection: {err}");
+                continue;
+            }
+        };
+
+        let result = read_request_body(&mut stream).and_then(|body| {
+            let request: AuditRequest = serde_json::from_str(&body)?;
+            handle(&request, &audits, &config)
+        });
+
+        let send_result = match result {
+            Ok(response) => respond(&mut stream, "200 OK", &serde_json::to_string(&response)?),
+            Err(err) => respond(&mut stream, "400 Bad Request", &serde_json::to_string(&ErrorResponse { error: err.to_string() })?),
+        };
+        if let Err(err) = send_result {
+            eprintln!("warning: failed to write response: {err}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Implementation of `zizmor stats`: aggregate posture metrics over a
+//! directory of workflows - pinning rate, most-used third-party owners,
+//! workflows missing a top-level permissions block, and trigger
+//! distribution - as a table and a JSON section, for tracking hardening
+//! progress over time.
+//!
+//! Scoped to a local directory for now; aggregating the same metrics
+//! across an org needs a real [`zizmor::org_scan::RepoSource`] wired to
+//! the GitHub API, which this crate doesn't have yet.
+
+use std::collections::BTreeMap;
+
+use camino::Utf8Path;
+
+use zizmor::models::{trigger_names, Uses, Workflow};
+
+struct Stats {
+    total_uses: usize,
+    pinned_uses: usize,
+    owner_counts: BTreeMap<String, usize>,
+    workflows_scanned: usize,
+    workflows_missing_permissions: usize,
+    trigger_counts: BTreeMap<String, usize>,
+}
+
+fn collect(path: &Utf8Path) -> Stats {
+    let mut stats = Stats {
+        total_uses: 0,
+        pinned_uses: 0,
+        owner_counts: BTreeMap::new(),
+        workflows_scanned: 0,
+        workflows_missing_permissions: 0,
+        trigger_counts: BTreeMap::new(),
+    };
+
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) || matches!(entry_path.file_stem(), Some("action")) {
+            continue;
+        }
+        let Ok(workflow) = Workflow::from_file(entry_path) else { continue };
+
+        stats.workflows_scanned += 1;
+        if workflow.permissions.is_none() {
+            stats.workflows_missing_permissions += 1;
+        }
+        for trigger in trigger_names(&workflow.on) {
+            *stats.trigger_counts.entry(trigger).or_default() += 1;
+        }
+        for job in workflow.jobs.values() {
+            for step in &job.steps {
+                let Some(raw) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw) else { continue };
+                stats.total_uses += 1;
+                if !uses.unpinned() {
+                    stats.pinned_uses += 1;
+                }
+                *stats.owner_counts.entry(uses.owner.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    stats
+}
+
+fn pinned_pct(stats: &Stats) -> f64 {
+    if stats.total_uses == 0 {
+        0.0
+    } else {
+        100.0 * stats.pinned_uses as f64 / stats.total_uses as f64
+    }
+}
+
+fn render_table(stats: &Stats) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("workflows scanned:          {}\n", stats.workflows_scanned));
+    out.push_str(&format!("missing permissions block:  {}\n", stats.workflows_missing_permissions));
+    out.push_str(&format!("uses: references:           {}\n", stats.total_uses));
+    out.push_str(&format!("SHA-pinned:                 {:.1}%\n", pinned_pct(stats)));
+
+    out.push_str("\nmost-used owners:\n");
+    let mut owners: Vec<(&String, &usize)> = stats.owner_counts.iter().collect();
+    owners.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    for (owner, count) in owners.into_iter().take(10) {
+        out.push_str(&format!("  {owner:<30} {count}\n"));
+    }
+
+    out.push_str("\ntrigger distribution:\n");
+    let mut triggers: Vec<(&String, &usize)> = stats.trigger_counts.iter().collect();
+    triggers.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    for (trigger, count) in triggers {
+        out.push_str(&format!("  {trigger:<30} {count}\n"));
+    }
+
+    out
+}
+
+fn render_json(stats: &Stats) -> anyhow::Result<String> {
+    let value = serde_json::json!({
+        "workflows_scanned": stats.workflows_scanned,
+        "workflows_missing_permissions": stats.workflows_missing_permissions,
+        "uses_total": stats.total_uses,
+        "uses_pinned": stats.pinned_uses,
+        "uses_pinned_pct": pinned_pct(stats),
+        "owner_counts": stats.owner_counts,
+        "trigger_counts": stats.trigger_counts,
+    });
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+pub fn run(path: &Utf8Path) -> anyhow::Result<()> {
+    let stats = collect(path);
+    print!("{}", render_table(&stats));
+    println!();
+    println!("{}", render_json(&stats)?);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Implementation of `zizmor suggest-updater`: inspects a repository's
+//! `uses:` references and prints the Renovate or Dependabot config
+//! needed to keep pins fresh once `zizmor pin` has pinned them to SHAs
+//! - closing the loop between pinning and staying current.
+
+use camino::Utf8Path;
+use zizmor::models::{Uses, Workflow};
+
+use crate::cli::UpdaterFormat;
+
+/// The distinct `owner/repo`s referenced by any `uses:` under `path`,
+/// sorted for stable output.
+fn distinct_owners(path: &Utf8Path) -> anyhow::Result<Vec<String>> {
+    let mut owners = vec![];
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        let Ok(workflow) = Workflow::from_file(entry_path) else { continue };
+        for job in workflow.jobs.values() {
+            for step in &job.steps {
+                let Some(raw) = &step.uses else { continue };
+                let Some(uses) = Uses::parse(raw) else { continue };
+                let owner_repo = uses.owner_repo();
+                if !owners.contains(&owner_repo) {
+                    owners.push(owner_repo);
+                }
+            }
+        }
+    }
+    owners.sort();
+    Ok(owners)
+}
+
+pub fn run(path: &Utf8Path, format: UpdaterFormat) -> anyhow::Result<()> {
+    let owners = distinct_owners(path)?;
+    if owners.is_empty() {
+        println!("no `uses:` references found under {path}; nothing to manage");
+        return Ok(());
+    }
+
+    println!("# found {} action(s) under {path}: {}", owners.len(), owners.join(", "));
+    match format {
+        UpdaterFormat::Renovate => println!(
+            r#"{{
+  "extends": ["helpers:pinGitHubActionDigests"]
+}}"#
+        ),
+        UpdaterFormat::Dependabot => println!(
+            r#"version: 2
+updates:
+  - package-ecosystem: "github-actions"
+    directory: "/"
+    schedule:
+      interval: "weekly""#
+        ),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! Implementation of `zizmor update-pins`: finds existing SHA pins and
+//! refreshes both the SHA and its `# vX.Y.Z` version comment, acting as a
+//! lightweight, offline-friendly Dependabot alternative.
+
+use camino::Utf8Path;
+use regex::Regex;
+
+/// A single `owner/repo@sha # vX.Y.Z` pin found in a workflow file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExistingPin {
+    pub owner_repo: String,
+    pub sha: String,
+    pub version_comment: Option<String>,
+}
+
+fn pin_re() -> Regex {
+    // `uses: owner/repo[/path]@<40-hex-sha>` optionally followed by a
+    // trailing `# vX.Y.Z`-style comment on the same line.
+    Regex::new(r"uses:\s*([\w.-]+/[\w.-]+(?:/[\w./-]+)?)@([0-9a-f]{40})(?:\s*#\s*(v?[\w.+-]+))?").unwrap()
+}
+
+/// Finds every existing SHA pin in `contents`.
+pub fn find_pins(contents: &str) -> Vec<ExistingPin> {
+    pin_re()
+        .captures_iter(contents)
+        .map(|cap| ExistingPin {
+            owner_repo: cap[1].to_string(),
+            sha: cap[2].to_string(),
+            version_comment: cap.get(3).map(|m| m.as_str().to_string()),
+        })
+        .collect()
+}
+
+/// Resolver for "what does the latest release of this action look
+/// like"; left as a seam so `update-pins` can be unit tested without a
+/// network dependency, and so the GraphQL client (see the batched-query
+/// follow-up) can implement it directly.
+pub trait LatestReleaseResolver {
+    fn latest(&self, owner_repo: &str) -> anyhow::Result<Option<(String, String)>>;
+}
+
+pub fn run(path: &Utf8Path, resolver: &dyn LatestReleaseResolver) -> anyhow::Result<()> {
+    for entry in walkdir::WalkDir::new(path).into_iter().filter_map(Result::ok) {
+        let entry_path = Utf8Path::from_path(entry.path()).unwrap_or(path);
+        if !matches!(entry_path.extension(), Some("yml") | Some("yaml")) {
+            continue;
+        }
+        let contents = std::fs::read_to_string(entry_path)?;
+        let mut updated = contents.clone();
+        for pin in find_pins(&contents) {
+            let Some((sha, version)) = resolver.latest(&pin.owner_repo)? else {
+                continue;
+            };
+            if sha == pin.sha {
+                continue;
+            }
+            let old = format!(
+                "{}@{}{}",
+                pin.owner_repo,
+                pin.sha,
+                pin.version_comment
+                    .as_deref()
+                    .map(|v| format!(" # {v}"))
+                    .unwrap_or_default()
+            );
+            let new = format!("{}@{sha} # {version}", pin.owner_repo);
+            updated = updated.replace(&old, &new);
+        }
+        if updated != contents {
+            std::fs::write(entry_path, updated)?;
+            println!("updated pins in {entry_path}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_pin_with_version_comment() {
+        let contents = "uses: actions/checkout@1111111111111111111111111111111111111111 # v4.1.0\n";
+        let pins = find_pins(contents);
+        assert_eq!(pins.len(), 1);
+        assert_eq!(pins[0].owner_repo, "actions/checkout");
+        assert_eq!(pins[0].version_comment.as_deref(), Some("v4.1.0"));
+    }
+}
@@ -0,0 +1,94 @@
+//! Implementation of `zizmor webhook`: a minimal blocking HTTP/1.1
+//! receiver for GitHub App webhooks (`push`/`pull_request`), built on
+//! the same raw `std::net::TcpListener` loop as `bin_support::serve`.
+//! Verifies `X-Hub-Signature-256` against the configured secret, then
+//! hands the event off to [`zizmor::webhook::handle_event`].
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+use zizmor::registry::default_audits;
+use zizmor::webhook::WorkflowFetcher;
+
+/// No GitHub API client is wired up yet, so there are no workflow files
+/// to fetch for a received event - see [`zizmor::webhook::WorkflowFetcher`].
+struct Unresolved;
+impl WorkflowFetcher for Unresolved {
+    fn fetch(&self, _repo: &str, _sha: &str) -> anyhow::Result<Vec<(String, String)>> {
+        Ok(vec![])
+    }
+}
+
+struct Request {
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+fn read_request(stream: &mut impl Read) -> anyhow::Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            anyhow::bail!("connection closed before headers finished");
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            headers.insert(key.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+    let content_length = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Request { headers, body: String::from_utf8(body)? })
+}
+
+fn respond(stream: &mut impl Write, status: &str, body: &str) -> anyhow::Result<()> {
+    write!(stream, "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len())?;
+    Ok(())
+}
+
+pub fn run(addr: &str, secret: Option<&str>) -> anyhow::Result<()> {
+    let audits = default_audits();
+    let config = zizmor::config::Config::default();
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("listening for GitHub webhooks on http://{addr}");
+
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                eprintln!("warning: failed to accept connection: {err}");
+                continue;
+            }
+        };
+
+        let result = (|| -> anyhow::Result<String> {
+            let request = read_request(&mut stream)?;
+            if let Some(secret) = secret {
+                let signature = request.headers.get("x-hub-signature-256").map(String::as_str).unwrap_or_default();
+                if !zizmor::webhook::verify_signature(secret.as_bytes(), request.body.as_bytes(), signature) {
+                    anyhow::bail!("signature verification failed");
+                }
+            }
+            let event = request.headers.get("x-github-event").cloned().unwrap_or_default();
+            let payload: serde_json::Value = serde_json::from_str(&request.body)?;
+            let findings = zizmor::webhook::handle_event(&event, &payload, &Unresolved, &audits, &config)?;
+            Ok(serde_json::to_string(&findings)?)
+        })();
+
+        let send_result = match result {
+            Ok(body) => respond(&mut stream, "200 OK", &body),
+            Err(err) => respond(&mut stream, "400 Bad Request", &serde_json::json!({"error": err.to_string()}).to_string()),
+        };
+        if let Err(err) = send_result {
+            eprintln!("warning: failed to write response: {err}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! A cross-run, content-addressed cache of per-file findings, so
+//! re-auditing a file whose content, config, and ruleset haven't
+//! changed since the last run can be skipped entirely - the gap
+//! [`crate::pre_commit`] already flags as needing "a persistent result
+//! cache keyed by file content hash".
+
+use sha2::{Digest, Sha256};
+
+use crate::finding::Finding;
+
+/// Where cache entries live, unless overridden by `ZIZMOR_CACHE_DIR`.
+const DEFAULT_CACHE_DIR: &str = ".zizmor-cache";
+
+/// The directory cache entries are read from and written to.
+pub fn cache_dir() -> camino::Utf8PathBuf {
+    std::env::var("ZIZMOR_CACHE_DIR")
+        .map(camino::Utf8PathBuf::from)
+        .unwrap_or_else(|_| camino::Utf8PathBuf::from(DEFAULT_CACHE_DIR))
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Builds a cache key from everything that can change a file's
+/// findings: its path, its own content, the effective config, the
+/// zizmor version, and the set of audit ids that will run over it. Any
+/// difference in any of these is a different key, so a stale entry is
+/// never served. `path` is included (not just content) because cached
+/// [`Finding`]s carry their originating file's path baked into their
+/// locations - two distinct files with identical content would
+/// otherwise share a cache entry and silently hand each other's path to
+/// the wrong file's findings.
+pub fn key(path: &str, content: &str, config_fingerprint: &str, audit_ids: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(path.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(content.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(config_fingerprint.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    for id in audit_ids {
+        hasher.update(b"\0");
+        hasher.update(id.as_bytes());
+    }
+    hex_encode(&hasher.finalize())
+}
+
+/// Loads the findings cached under `key` in `dir`, if a readable and
+/// parseable entry exists.
+pub fn load(dir: &camino::Utf8Path, key: &str) -> Option<Vec<Finding>> {
+    let raw = std::fs::read_to_string(dir.join(key)).ok()?;
+    serde_json::from_str::<Vec<Finding>>(&raw).ok()
+}
+
+/// Stores `findings` under `key` in `dir`, creating `dir` if needed.
+/// Best-effort: a write failure (a read-only cache dir, a full disk)
+/// just means the next run re-audits this file instead of failing the
+/// whole scan.
+pub fn store(dir: &camino::Utf8Path, key: &str, findings: &[Finding]) {
+    if std::fs::create_dir_all(dir).is_err() {
+        return;
+    }
+    if let Ok(raw) = serde_json::to_string(findings) {
+        let _ = std::fs::write(dir.join(key), raw);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_changes_with_content() {
+        assert_ne!(key("w.yml", "a", "cfg", &["rule"]), key("w.yml", "b", "cfg", &["rule"]));
+    }
+
+    #[test]
+    fn key_changes_with_audit_set() {
+        assert_ne!(
+            key("w.yml", "a", "cfg", &["rule"]),
+            key("w.yml", "a", "cfg", &["rule", "other-rule"])
+        );
+    }
+
+    #[test]
+    fn key_is_stable_for_identical_inputs() {
+        assert_eq!(key("w.yml", "a", "cfg", &["rule"]), key("w.yml", "a", "cfg", &["rule"]));
+    }
+
+    #[test]
+    fn key_changes_with_path_for_identical_content() {
+        assert_ne!(key("a.yml", "same", "cfg", &["rule"]), key("b.yml", "same", "cfg", &["rule"]));
+    }
+}
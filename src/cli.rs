@@ -0,0 +1,354 @@
+//! Command-line argument parsing.
+
+use camino::Utf8PathBuf;
+use clap::Parser;
+use zizmor::persona::Persona;
+
+/// Config dialect `zizmor suggest-updater` emits.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum UpdaterFormat {
+    Renovate,
+    Dependabot,
+}
+
+/// Bill-of-materials dialect `zizmor sbom` emits.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum SbomFormat {
+    CycloneDx,
+    Spdx,
+}
+
+/// Output dialect `zizmor graph` emits.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+/// Output dialect `zizmor rules` emits.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum RulesFormat {
+    Json,
+    Table,
+}
+
+/// Output dialect for the normal findings report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Default)]
+pub enum OutputFormat {
+    #[default]
+    Plain,
+    /// SonarQube's Generic Issue Import JSON format.
+    Sonar,
+    /// SARIF 2.1.0, as consumed by GitHub code scanning.
+    Sarif,
+    /// Findings as JSON, with each location annotated with its
+    /// `CODEOWNERS` owners when a `CODEOWNERS` file is found.
+    Json,
+    /// Findings as a Markdown table, with a CODEOWNERS column when a
+    /// `CODEOWNERS` file is found.
+    Markdown,
+    /// One line per finding, `file:line:col: severity[rule]: message`,
+    /// stable enough for an editor problem matcher or a grep pipeline.
+    Compact,
+}
+
+/// CLI-facing mirror of [`zizmor::finding::Severity`] - `clap::ValueEnum`
+/// needs to be derived on the type actually parsed from argv, and that
+/// derive doesn't belong on a core library type with no CLI of its own.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum SeverityArg {
+    Unknown,
+    Informational,
+    Low,
+    Medium,
+    High,
+}
+
+impl From<SeverityArg> for zizmor::finding::Severity {
+    fn from(arg: SeverityArg) -> Self {
+        match arg {
+            SeverityArg::Unknown => Self::Unknown,
+            SeverityArg::Informational => Self::Informational,
+            SeverityArg::Low => Self::Low,
+            SeverityArg::Medium => Self::Medium,
+            SeverityArg::High => Self::High,
+        }
+    }
+}
+
+/// CLI-facing mirror of [`zizmor::finding::Confidence`]; see [`SeverityArg`].
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ConfidenceArg {
+    Low,
+    Medium,
+    High,
+}
+
+impl From<ConfidenceArg> for zizmor::finding::Confidence {
+    fn from(arg: ConfidenceArg) -> Self {
+        match arg {
+            ConfidenceArg::Low => Self::Low,
+            ConfidenceArg::Medium => Self::Medium,
+            ConfidenceArg::High => Self::High,
+        }
+    }
+}
+
+/// When to colorize `--format plain` output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, Default)]
+pub enum ColorChoice {
+    /// Color if stdout is a terminal and `NO_COLOR` isn't set.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Standalone subcommands that don't run the normal audit pipeline.
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Pin every action and reusable workflow reference to a full SHA,
+    /// independent of running audits.
+    Pin {
+        /// Workflow/action directory to rewrite in place; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+    },
+    /// Find existing SHA pins, look up the latest upstream release, and
+    /// rewrite both the SHA and the `# vX.Y.Z` comment.
+    UpdatePins {
+        /// Workflow/action directory to rewrite in place; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+    },
+    /// Audit two directories (or git refs, checked out via `git
+    /// archive`) and report which findings were added, removed, or are
+    /// unchanged between them - the security delta of a refactor or a
+    /// template rollout.
+    Diff {
+        /// The "before" ref or directory.
+        a: String,
+        /// The "after" ref or directory.
+        b: String,
+    },
+    /// Inspect this repository's workflows and write a starter
+    /// `zizmor.yml` with triggers, self-hosted labels, and `uses:`
+    /// owners found in them pre-filled, for a lower-friction adoption
+    /// than starting from a blank config.
+    Init {
+        /// Workflow/action directory to scan; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+        /// Where to write the generated config.
+        #[arg(long, default_value = "zizmor.yml")]
+        output: Utf8PathBuf,
+    },
+    /// Dump zizmor's resolved intermediate representation of a workflow
+    /// (normalized triggers, per-job effective permissions/env, taint
+    /// facts, uses graph) as JSON.
+    Ir {
+        /// Workflow file to analyze.
+        path: Utf8PathBuf,
+    },
+    /// Inspect the repository's `uses:` references and print the
+    /// Renovate or Dependabot config needed to keep pins fresh.
+    SuggestUpdater {
+        /// Workflow/action directory to scan; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+        /// Config dialect to emit.
+        #[arg(long, value_enum, default_value = "dependabot")]
+        format: UpdaterFormat,
+    },
+    /// Emit a software bill of materials covering every action, reusable
+    /// workflow, and container image referenced by the scanned workflows.
+    Sbom {
+        /// Workflow/action directory to scan; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+        /// BOM dialect to emit.
+        #[arg(long, value_enum, default_value = "cyclone-dx")]
+        format: SbomFormat,
+    },
+    /// Export the workflow -> job -> uses/reusable-workflow-call graph,
+    /// including `workflow_run` edges between workflows, for
+    /// visualizing CI trust relationships.
+    Graph {
+        /// Workflow directory to scan; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+        /// Output dialect to emit.
+        #[arg(long, value_enum, default_value = "dot")]
+        format: GraphFormat,
+    },
+    /// Print a rule's description, rationale, severity, example, and
+    /// remediation from zizmor's embedded docs, offline.
+    Explain {
+        /// The rule id to explain, e.g. `unpinned-uses`.
+        rule_id: String,
+    },
+    /// List every built-in audit's id, persona, and network needs.
+    Rules {
+        /// Output dialect to emit.
+        #[arg(long, value_enum, default_value = "table")]
+        format: RulesFormat,
+    },
+    /// Run a long-lived HTTP server exposing `POST /audit`, keeping the
+    /// audit set and config warm across requests.
+    Serve {
+        /// Address to listen on.
+        #[arg(long, default_value = "127.0.0.1:8844")]
+        addr: String,
+    },
+    /// Report aggregate posture metrics - pinning rate, most-used
+    /// owners, workflows missing a permissions block, trigger
+    /// distribution - as a table and JSON, for tracking hardening
+    /// progress over time.
+    Stats {
+        /// Workflow/action directory to scan; defaults to `.github`.
+        path: Option<Utf8PathBuf>,
+    },
+    /// Run a GitHub App webhook receiver that audits workflow files on
+    /// `push`/`pull_request` events.
+    Webhook {
+        /// Address to listen on.
+        #[arg(long, default_value = "127.0.0.1:8845")]
+        addr: String,
+        /// Webhook secret to verify `X-Hub-Signature-256` against; if
+        /// omitted, signatures aren't checked.
+        #[arg(long)]
+        secret: Option<String>,
+    },
+}
+
+#[derive(Debug, Parser)]
+#[command(name = "zizmor", about = "A static analysis tool for GitHub Actions")]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Workflow or action files (or directories) to audit.
+    pub inputs: Vec<Utf8PathBuf>,
+
+    /// Path to a `zizmor.yml` config file.
+    #[arg(long)]
+    pub config: Option<Utf8PathBuf>,
+
+    /// Don't perform any network access.
+    #[arg(long)]
+    pub offline: bool,
+
+    /// Which built-in audit profile to run.
+    #[arg(long, value_enum, default_value = "regular")]
+    pub persona: Persona,
+
+    /// GitHub token used by online audits, overriding GH_TOKEN/GITHUB_TOKEN
+    /// and `gh auth token`.
+    #[arg(long)]
+    pub gh_token: Option<String>,
+
+    /// Refuse to honor inline/repo-local suppressions or severity
+    /// downgrades relative to this config; for central policy gates.
+    #[arg(long)]
+    pub enforce_policy: bool,
+
+    /// Output format for the findings report.
+    #[arg(long, value_enum, default_value = "plain")]
+    pub format: OutputFormat,
+
+    /// Apply audit-suggested fixes in place.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// With --fix, print a unified diff instead of writing files.
+    #[arg(long, requires = "fix")]
+    pub dry_run: bool,
+
+    /// Only run these comma-separated rule ids for this invocation,
+    /// overriding the config's enabled/disabled rules. With --fix, this
+    /// also limits which fixes are applied.
+    #[arg(long, value_delimiter = ',')]
+    pub only: Vec<String>,
+
+    /// Don't run these comma-separated rule ids for this invocation,
+    /// overriding the config. Applied after --only.
+    #[arg(long, value_delimiter = ',')]
+    pub ignore: Vec<String>,
+
+    /// Fast mode tuned for pre-commit hooks: skips network audits
+    /// unless a GitHub token is explicitly supplied, and stops running
+    /// further audits once --timeout-secs is spent.
+    #[arg(long)]
+    pub pre_commit: bool,
+
+    /// With --pre-commit, the wall-clock budget in seconds before
+    /// zizmor stops running further audits and reports what it has.
+    #[arg(long, default_value = "5", requires = "pre_commit")]
+    pub timeout_secs: u64,
+
+    /// Post findings as inline PR review comments. Takes an explicit
+    /// `owner/repo#123` reference, or nothing to auto-detect the
+    /// current PR from `GITHUB_REPOSITORY`/`GITHUB_REF` when running in
+    /// Actions.
+    #[arg(long, num_args = 0..=1, default_missing_value = "auto")]
+    pub post_review: Option<String>,
+
+    /// Print `known-vulnerable-action` matches as an OSV batch document
+    /// instead of (or alongside) the normal findings output, for
+    /// aggregation with other scanners.
+    #[arg(long)]
+    pub export_osv: bool,
+
+    /// Print a per-check pass/fail summary alongside the normal findings
+    /// output, for the OpenSSF Scorecard checks zizmor's rules overlap
+    /// with (Token-Permissions, Pinned-Dependencies, Dangerous-Workflow).
+    #[arg(long)]
+    pub scorecard: bool,
+
+    /// Upload the findings as SARIF straight to the GitHub code scanning
+    /// API, resolving repo/ref/sha from the Actions environment, instead
+    /// of writing a `--format sarif` file for a separate upload step.
+    #[arg(long)]
+    pub upload_sarif: bool,
+
+    /// Post a summary of new findings (relative to --baseline, if given)
+    /// to a webhook or Slack-compatible URL at the end of the run.
+    #[arg(long)]
+    pub notify: Option<String>,
+
+    /// A previous `--format json` run to diff against when using
+    /// --notify; without it, every finding counts as new.
+    #[arg(long, requires = "notify")]
+    pub baseline: Option<Utf8PathBuf>,
+
+    /// Cache per-file findings across runs, keyed by file content,
+    /// config, and the active rule set, so unchanged files are skipped
+    /// on the next invocation. Stored under `ZIZMOR_CACHE_DIR`, or
+    /// `.zizmor-cache` by default.
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Print a per-audit, per-file wall-time report (table and JSON) to
+    /// stderr after the scan, so slow audits can be identified directly
+    /// instead of guessed at.
+    #[arg(long)]
+    pub timings: bool,
+
+    /// When to colorize --format plain output. Defaults to coloring
+    /// only when stdout is a terminal and NO_COLOR isn't set.
+    #[arg(long, value_enum, default_value = "auto")]
+    pub color: ColorChoice,
+
+    /// Zero tolerated ambiguity: runs with the `auditor` persona
+    /// regardless of --persona, fails on any finding (not just
+    /// medium-or-above), and fails on warnings that would otherwise
+    /// just be printed - an expired suppression, a malformed `uses:`
+    /// string, or a --pre-commit budget that cut a scan short.
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Only report findings at or above this severity. Filters the
+    /// report only - the exit code still reflects every finding, so a
+    /// hidden high-severity issue can't silently pass CI.
+    #[arg(long, value_enum)]
+    pub min_severity: Option<SeverityArg>,
+
+    /// Only report findings at or above this confidence. Same
+    /// report-only scope as --min-severity.
+    #[arg(long, value_enum)]
+    pub min_confidence: Option<ConfidenceArg>,
+}
@@ -0,0 +1,8 @@
+//! Thin wrapper around the system clock, so expiry checks (suppressions,
+//! cached results) have a single seam to mock in tests.
+
+use chrono::NaiveDate;
+
+pub fn today() -> NaiveDate {
+    chrono::Utc::now().date_naive()
+}
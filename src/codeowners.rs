@@ -0,0 +1,96 @@
+//! Parses a `CODEOWNERS` file and resolves which owners are responsible
+//! for a given path, so large-org scans can route findings to the right
+//! team instead of a single unowned queue.
+//!
+//! Pattern matching here is a simplified subset of `.gitignore` syntax -
+//! a leading `/` anchors to the root, a trailing `/*` or `/**` matches
+//! everything under a directory, and anything else matches by suffix -
+//! rather than a full glob engine; this covers the common
+//! `/path/to/dir/` and `*.yml` forms CODEOWNERS files actually use in
+//! practice.
+
+/// One `CODEOWNERS` line: a pattern and the owners it assigns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub pattern: String,
+    pub owners: Vec<String>,
+}
+
+/// Parses `raw`, skipping blank lines and `#` comments, in file order -
+/// callers should keep that order, since CODEOWNERS uses last-match-wins.
+pub fn parse(raw: &str) -> Vec<Entry> {
+    raw.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                return None;
+            }
+            let mut parts = line.split_whitespace();
+            let pattern = parts.next()?.to_string();
+            let owners = parts.map(str::to_string).collect();
+            Some(Entry { pattern, owners })
+        })
+        .collect()
+}
+
+fn matches(pattern: &str, path: &str) -> bool {
+    if let Some(dir) = pattern.strip_suffix("/**").or_else(|| pattern.strip_suffix("/*")) {
+        let dir = dir.trim_start_matches('/');
+        return path == dir || path.starts_with(&format!("{dir}/"));
+    }
+    if let Some(rooted) = pattern.strip_prefix('/') {
+        return path == rooted || path.starts_with(&format!("{rooted}/"));
+    }
+    if let Some(suffix) = pattern.strip_prefix('*') {
+        return path.ends_with(suffix);
+    }
+    path == pattern || path.ends_with(&format!("/{pattern}"))
+}
+
+/// Conventional locations a `CODEOWNERS` file may live in, checked in
+/// this order, mirroring GitHub's own lookup.
+const CONVENTIONAL_PATHS: &[&str] = &["CODEOWNERS", ".github/CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Loads and parses the first `CODEOWNERS` file found at a conventional
+/// location under `root`, or an empty list if none exists.
+pub fn load(root: &camino::Utf8Path) -> Vec<Entry> {
+    for candidate in CONVENTIONAL_PATHS {
+        let path = root.join(candidate);
+        if let Ok(raw) = std::fs::read_to_string(&path) {
+            return parse(&raw);
+        }
+    }
+    vec![]
+}
+
+/// The owners of `path` per CODEOWNERS last-match-wins semantics: later
+/// entries override earlier ones, and a path with no matching pattern
+/// has no owners.
+pub fn owners_for<'a>(entries: &'a [Entry], path: &str) -> Option<&'a [String]> {
+    entries.iter().rev().find(|entry| matches(&entry.pattern, path)).map(|entry| entry.owners.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_entries_and_skips_comments() {
+        let entries = parse("# comment\n*.yml @infra-team\n/.github/ @platform-team @security-team\n");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].owners, vec!["@platform-team".to_string(), "@security-team".to_string()]);
+    }
+
+    #[test]
+    fn later_entry_wins() {
+        let entries = parse("*.yml @infra-team\n/.github/workflows/release.yml @release-team\n");
+        assert_eq!(owners_for(&entries, ".github/workflows/release.yml"), Some(&["@release-team".to_string()][..]));
+        assert_eq!(owners_for(&entries, ".github/workflows/ci.yml"), Some(&["@infra-team".to_string()][..]));
+    }
+
+    #[test]
+    fn unmatched_path_has_no_owners() {
+        let entries = parse("*.yml @infra-team\n");
+        assert_eq!(owners_for(&entries, "README.md"), None);
+    }
+}
@@ -0,0 +1,95 @@
+//! Extracts `#`-to-end-of-line comments from a document's raw YAML
+//! source, since `serde_yaml` discards them during parsing. Gives a
+//! common foundation for features that need to see a comment rather
+//! than just the value next to it - pin-version-comment validation,
+//! inline suppressions, comment-preserving autofixes - without each one
+//! re-deriving its own ad hoc scan of the raw text.
+
+/// A single comment found in a document's raw source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    /// Byte offset of the `#` itself.
+    pub start: usize,
+    /// Byte offset one past the end of the comment, exclusive of the
+    /// line's trailing newline (if any).
+    pub end: usize,
+    /// 1-indexed line number the comment starts on.
+    pub line: usize,
+    /// The comment's text, with the leading `#` and (if present) one
+    /// leading space stripped.
+    pub text: String,
+}
+
+/// Scans `raw` for comments, skipping any `#` that appears inside a
+/// single- or double-quoted scalar.
+///
+/// This is a best-effort lexical scan, not a real YAML tokenizer: it
+/// doesn't track block/flow context, so a `#` inside an unquoted plain
+/// scalar that itself looks like the start of a comment (rare in
+/// practice for workflow YAML) would be misread as one. That's the same
+/// tradeoff [`crate::fix`] and [`crate::span`] already make by working
+/// against the raw text rather than carrying spans from the parse.
+pub fn parse_comments(raw: &str) -> Vec<Comment> {
+    let mut comments = vec![];
+    let mut line = 1usize;
+    let mut in_single = false;
+    let mut in_double = false;
+    let bytes = raw.as_bytes();
+
+    let mut i = 0;
+    while i < raw.len() {
+        match bytes[i] {
+            b'\n' => line += 1,
+            b'\'' if !in_double => in_single = !in_single,
+            b'"' if !in_single => in_double = !in_double,
+            b'#' if !in_single && !in_double => {
+                let preceded_by_space = i == 0 || matches!(bytes[i - 1], b' ' | b'\t' | b'\n');
+                if preceded_by_space {
+                    let end = raw[i..].find('\n').map(|o| i + o).unwrap_or(raw.len());
+                    let text = raw[i + 1..end].trim_start().to_string();
+                    comments.push(Comment { start: i, end, line, text });
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    comments
+}
+
+/// The comment, if any, that starts on the same line as the byte offset
+/// `pos`. Useful for finding the trailing `# ...` comment on a line a
+/// caller has already located via [`str::find`].
+pub fn comment_on_line_of(raw: &str, comments: &[Comment], pos: usize) -> Option<Comment> {
+    let target_line = raw[..pos].matches('\n').count() + 1;
+    comments.iter().find(|c| c.line == target_line).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_trailing_comment() {
+        let raw = "uses: actions/checkout@v4 # pinned\n";
+        let comments = parse_comments(raw);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].text, "pinned");
+    }
+
+    #[test]
+    fn ignores_hash_inside_quoted_string() {
+        let raw = "run: echo \"#not-a-comment\"\n";
+        assert_eq!(parse_comments(raw), vec![]);
+    }
+
+    #[test]
+    fn finds_comment_on_the_same_line_as_a_position() {
+        let raw = "a: 1\nb: 2 # note\nc: 3\n";
+        let comments = parse_comments(raw);
+        let pos = raw.find("b: 2").unwrap();
+        let found = comment_on_line_of(raw, &comments, pos).unwrap();
+        assert_eq!(found.text, "note");
+    }
+}
@@ -0,0 +1,434 @@
+//! `zizmor.yml` configuration: per-rule ignores, severity overrides, and
+//! other knobs that let users tune audits without forking them.
+
+use std::collections::HashMap;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Deserialize;
+
+use crate::audit::custom_rule::CustomRule;
+use crate::finding::Severity;
+
+pub mod validate;
+
+/// What a [`PinningRule`] requires of a matching `uses:` reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PinRequirement {
+    /// Must be pinned to a full commit SHA.
+    Sha,
+    /// A tag (or SHA) is acceptable.
+    Tag,
+    /// Any ref, including a mutable branch name, is acceptable.
+    Any,
+}
+
+/// A per-owner pinning requirement, e.g. "third parties must use a SHA,
+/// but our own org's actions may use tags".
+#[derive(Debug, Clone, Deserialize)]
+pub struct PinningRule {
+    pub owner: String,
+    pub require: PinRequirement,
+}
+
+/// A single path/job/step-scoped exception for a rule.
+///
+/// Any of `workflow`, `job`, or `step` left unset means "match any".
+#[derive(Debug, Clone, Deserialize)]
+pub struct IgnoreRule {
+    /// Rule id to suppress, e.g. `self-hosted-runner`.
+    pub rule: String,
+    /// Glob matched against the workflow file path, e.g. `benchmarks.yml`.
+    #[serde(default)]
+    pub workflow: Option<String>,
+    /// Job id to scope the suppression to.
+    #[serde(default)]
+    pub job: Option<String>,
+    /// Step id (or 0-based index) to scope the suppression to.
+    #[serde(default)]
+    pub step: Option<String>,
+    /// Once this date passes, the suppression no longer applies and is
+    /// itself reported as stale via [`Config::expired_ignores`].
+    #[serde(default)]
+    pub expires: Option<chrono::NaiveDate>,
+    /// Why this suppression exists. Required when
+    /// `policy.require-suppression-reason` is set.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl IgnoreRule {
+    pub fn is_expired(&self, today: chrono::NaiveDate) -> bool {
+        self.expires.is_some_and(|expires| today >= expires)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Config {
+    /// Path-and-route-scoped rule suppressions.
+    #[serde(default)]
+    pub ignore: Vec<IgnoreRule>,
+    /// Per-rule severity overrides, e.g. `unpinned-uses: high`.
+    #[serde(default)]
+    pub severity_overrides: HashMap<String, Severity>,
+    /// Action owners/repos treated as first-party/trusted, e.g. `actions`
+    /// or `my-org/shared-workflows`. Consulted by audits like
+    /// `unpinned-uses`, `token-passing`, and typosquatting checks to
+    /// reduce noise for internal actions.
+    #[serde(default)]
+    pub trusted_owners: Vec<String>,
+    /// Per-owner pinning requirements. The first matching rule wins;
+    /// owners with no matching rule default to requiring a SHA.
+    #[serde(default)]
+    pub pinning_policy: Vec<PinningRule>,
+    /// House rules expressed declaratively instead of as Rust audits.
+    #[serde(default)]
+    pub custom_rules: Vec<CustomRule>,
+    /// Third-party WASM audit modules to load; see [`crate::plugin`].
+    #[serde(default)]
+    pub plugins: Vec<crate::plugin::PluginSpec>,
+    /// Small Rhai-scripted audits; see [`crate::audit::script_rule`].
+    #[serde(default)]
+    pub scripts: Vec<crate::audit::script_rule::ScriptSpec>,
+    /// A base config to inherit from before applying this file's own
+    /// settings. Currently only local paths (relative to this file) are
+    /// supported; `org/repo` and URL forms are left for a remote-fetch
+    /// follow-up since they need an offline-safe cache.
+    #[serde(default)]
+    pub extends: Option<Utf8PathBuf>,
+    /// Structured per-rule options, e.g. `missing-timeout: {max-minutes: 30}`.
+    /// Each audit is responsible for interpreting its own entry via
+    /// [`Config::rule_options`].
+    #[serde(default)]
+    pub rule_options: HashMap<String, serde_yaml::Value>,
+    /// Organizational policy knobs; see [`Policy`].
+    #[serde(default)]
+    pub policy: Policy,
+    /// First-party action/path designations; see [`FirstParty`].
+    #[serde(default)]
+    pub first_party: FirstParty,
+    /// Path (relative to this config file) to a local OSV-format JSON
+    /// feed of known-vulnerable/malicious actions, consulted by the
+    /// `known-vulnerable-action` audit.
+    #[serde(default)]
+    pub osv_feed_path: Option<Utf8PathBuf>,
+    /// Advisories loaded from `osv_feed_path`; populated on demand by
+    /// [`Config::ensure_osv_advisories_loaded`] rather than eagerly by
+    /// [`Config::from_file`], so a `--offline` run (or any run that
+    /// skips `known-vulnerable-action`) never pays to parse a feed it
+    /// won't consult. Not part of the on-disk schema itself.
+    #[serde(skip)]
+    pub osv_advisories: Vec<crate::osv::Advisory>,
+    /// Directory `osv_feed_path` is resolved relative to; the directory
+    /// this config file was loaded from. Not part of the on-disk schema.
+    #[serde(skip)]
+    config_dir: Utf8PathBuf,
+}
+
+/// First-party designations, distinct from [`Config::trusted_owners`]:
+/// trusted owners reduce noise from known-safe third parties, while
+/// first-party marks code audits should treat as *yours*, e.g. allowing
+/// secrets to flow into it freely.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FirstParty {
+    /// Action owners considered first-party, e.g. `my-org`.
+    #[serde(default)]
+    pub owners: Vec<String>,
+    /// Path prefixes (relative to the repo root) treated as first-party,
+    /// for local `./actions/...` composite actions.
+    #[serde(default)]
+    pub path_prefixes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Policy {
+    /// Reject suppressions (config `ignore` entries) that don't carry a
+    /// `reason:`, so exceptions are always accompanied by a justification.
+    #[serde(default)]
+    pub require_suppression_reason: bool,
+}
+
+impl Config {
+    /// Like [`Config::from_file`], but follows `extends:` chains,
+    /// merging each base's settings underneath the file that declares it.
+    /// Returns an error if an `extends` cycle is detected.
+    pub fn load_with_extends(path: &Utf8Path) -> anyhow::Result<Self> {
+        let mut seen = vec![];
+        Self::load_with_extends_inner(path, &mut seen)
+    }
+
+    fn load_with_extends_inner(path: &Utf8Path, seen: &mut Vec<Utf8PathBuf>) -> anyhow::Result<Self> {
+        let canonical = path.to_path_buf();
+        anyhow::ensure!(
+            !seen.contains(&canonical),
+            "extends cycle detected: {} was already visited",
+            canonical
+        );
+        seen.push(canonical);
+
+        let mut config = Self::from_file(path)?;
+        if let Some(base_path) = config.extends.take() {
+            let base_path = path.parent().unwrap_or(Utf8Path::new(".")).join(base_path);
+            let base = Self::load_with_extends_inner(&base_path, seen)?;
+            config = base.merged_with(config);
+        }
+        Ok(config)
+    }
+
+    /// Deserializes `rule`'s structured options into `T`, if configured.
+    /// Returns `Ok(None)` (not an error) when the rule has no entry, so
+    /// callers can fall back to `T::default()`.
+    pub fn rule_options<T: serde::de::DeserializeOwned>(&self, rule: &str) -> anyhow::Result<Option<T>> {
+        match self.rule_options.get(rule) {
+            Some(value) => Ok(Some(serde_yaml::from_value(value.clone())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether `owner` is designated first-party (distinct from, and
+    /// typically a subset of, [`Config::is_trusted_owner`]).
+    pub fn is_first_party_owner(&self, owner: &str) -> bool {
+        self.first_party.owners.iter().any(|o| o.eq_ignore_ascii_case(owner))
+    }
+
+    /// Whether `path` (a local `./`-prefixed action path) falls under a
+    /// configured first-party path prefix.
+    pub fn is_first_party_path(&self, path: &str) -> bool {
+        self.first_party
+            .path_prefixes
+            .iter()
+            .any(|prefix| path.starts_with(prefix.as_str()))
+    }
+
+    /// Suppressions that have passed their `expires:` date, so they can
+    /// be reported as stale rather than silently dropped.
+    pub fn expired_ignores(&self) -> impl Iterator<Item = &IgnoreRule> {
+        let today = crate::clock::today();
+        self.ignore.iter().filter(move |entry| entry.is_expired(today))
+    }
+
+    /// Merges a per-repository `zizmor.yml` underneath `self` acting as
+    /// the central/invoking config, for org-wide scans; see
+    /// [`crate::org_scan::effective_config_for`].
+    pub fn merge_repo_overlay(self, repo_config: Self) -> Self {
+        self.merged_with(repo_config)
+    }
+
+    /// Merges `self` as the base and `overlay` as the file that declared
+    /// `extends: self`; overlay entries are appended (for lists) or take
+    /// precedence (for maps/scalars). `pinning_policy` is the exception:
+    /// since [`Self::pin_requirement_for`] takes the first matching rule,
+    /// overlay rules are prepended so they win over a base rule for the
+    /// same owner instead of being shadowed by it.
+    fn merged_with(mut self, overlay: Self) -> Self {
+        self.ignore.extend(overlay.ignore);
+        self.severity_overrides.extend(overlay.severity_overrides);
+        self.trusted_owners.extend(overlay.trusted_owners);
+        self.pinning_policy = overlay.pinning_policy.into_iter().chain(self.pinning_policy).collect();
+        self.custom_rules.extend(overlay.custom_rules);
+        self.plugins.extend(overlay.plugins);
+        self.scripts.extend(overlay.scripts);
+        self.osv_advisories.extend(overlay.osv_advisories);
+        self.extends = None;
+        self
+    }
+}
+
+impl Config {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(raw: &str) -> anyhow::Result<Self> {
+        Ok(serde_yaml::from_str(raw)?)
+    }
+
+    pub fn from_file(path: &camino::Utf8Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let mut config = Self::from_str(&std::fs::read_to_string(path)?)?;
+        config.config_dir = path.parent().unwrap_or(camino::Utf8Path::new(".")).to_path_buf();
+        Ok(config)
+    }
+
+    /// Parses `osv_feed_path` (relative to the config file's own
+    /// directory) into `osv_advisories`, if configured and not already
+    /// loaded. A no-op otherwise, so callers can call this
+    /// unconditionally right before a scan that needs it - typically
+    /// skipped entirely for an offline run - without double-parsing the
+    /// feed on repeated calls.
+    pub fn ensure_osv_advisories_loaded(&mut self) -> anyhow::Result<()> {
+        if self.osv_advisories.is_empty() {
+            if let Some(feed_path) = &self.osv_feed_path {
+                let feed_path = self.config_dir.join(feed_path);
+                let feed_json = std::fs::read_to_string(&feed_path)?;
+                self.osv_advisories = crate::osv::load_advisories(&feed_json)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `rule` should be suppressed for the given
+    /// workflow path / job id / step id combination.
+    pub fn is_ignored(
+        &self,
+        rule: &str,
+        workflow_path: &camino::Utf8Path,
+        job: Option<&str>,
+        step: Option<&str>,
+    ) -> bool {
+        let today = crate::clock::today();
+        self.ignore.iter().any(|entry| {
+            if entry.rule != rule {
+                return false;
+            }
+            if entry.is_expired(today) {
+                return false;
+            }
+            if let Some(glob) = &entry.workflow {
+                let Ok(pattern) = glob::Pattern::new(glob) else {
+                    return false;
+                };
+                if !pattern.matches(workflow_path.as_str())
+                    && !pattern.matches(workflow_path.file_name().unwrap_or_default())
+                {
+                    return false;
+                }
+            }
+            if let Some(want_job) = &entry.job {
+                if job != Some(want_job.as_str()) {
+                    return false;
+                }
+            }
+            if let Some(want_step) = &entry.step {
+                if step != Some(want_step.as_str()) {
+                    return false;
+                }
+            }
+            true
+        })
+    }
+
+    /// Resolves the effective severity for a finding, honoring any
+    /// configured override for its rule id.
+    pub fn effective_severity(&self, rule: &str, default: Severity) -> Severity {
+        self.severity_overrides.get(rule).copied().unwrap_or(default)
+    }
+
+    /// Whether `owner` (or `owner/repo`) is configured as trusted/first-party.
+    ///
+    /// A bare owner entry (e.g. `actions`) trusts every repo under that
+    /// owner; an `owner/repo` entry trusts only that repo.
+    pub fn is_trusted_owner(&self, owner: &str, repo: &str) -> bool {
+        self.trusted_owners
+            .iter()
+            .any(|entry| entry.eq_ignore_ascii_case(owner) || entry.eq_ignore_ascii_case(&format!("{owner}/{repo}")))
+    }
+
+    /// Resolves the pinning requirement for `owner`, defaulting to
+    /// [`PinRequirement::Sha`] when no rule matches.
+    pub fn pin_requirement_for(&self, owner: &str) -> PinRequirement {
+        self.pinning_policy
+            .iter()
+            .find(|rule| rule.owner.eq_ignore_ascii_case(owner))
+            .map(|rule| rule.require)
+            .unwrap_or(PinRequirement::Sha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use camino::Utf8PathBuf;
+
+    #[test]
+    fn extends_merges_base_and_overlay_ignores() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = Utf8PathBuf::from_path_buf(dir.path().join("base.yml")).unwrap();
+        let overlay_path = Utf8PathBuf::from_path_buf(dir.path().join("overlay.yml")).unwrap();
+
+        std::fs::write(&base_path, "ignore:\n  - rule: unpinned-uses\n").unwrap();
+        std::fs::write(
+            &overlay_path,
+            "extends: base.yml\nignore:\n  - rule: excessive-permissions\n",
+        )
+        .unwrap();
+
+        let config = Config::load_with_extends(&overlay_path).unwrap();
+        assert_eq!(config.ignore.len(), 2);
+        assert_eq!(config.ignore[0].rule, "unpinned-uses");
+        assert_eq!(config.ignore[1].rule, "excessive-permissions");
+    }
+
+    #[test]
+    fn extends_overlay_pinning_policy_wins_over_base_for_same_owner() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = Utf8PathBuf::from_path_buf(dir.path().join("base.yml")).unwrap();
+        let overlay_path = Utf8PathBuf::from_path_buf(dir.path().join("overlay.yml")).unwrap();
+
+        std::fs::write(&base_path, "pinning-policy:\n  - owner: actions\n    require: sha\n").unwrap();
+        std::fs::write(
+            &overlay_path,
+            "extends: base.yml\npinning-policy:\n  - owner: actions\n    require: tag\n",
+        )
+        .unwrap();
+
+        let config = Config::load_with_extends(&overlay_path).unwrap();
+        assert_eq!(config.pin_requirement_for("actions"), PinRequirement::Tag);
+    }
+
+    #[test]
+    fn ignores_by_workflow_glob_and_job() {
+        let config = Config::from_str(
+            r#"
+ignore:
+  - rule: self-hosted-runner
+    workflow: benchmarks.yml
+    job: perf
+"#,
+        )
+        .unwrap();
+
+        let path = Utf8PathBuf::from("benchmarks.yml");
+        assert!(config.is_ignored("self-hosted-runner", &path, Some("perf"), None));
+        assert!(!config.is_ignored("self-hosted-runner", &path, Some("build"), None));
+        assert!(!config.is_ignored("unpinned-uses", &path, Some("perf"), None));
+    }
+
+    #[test]
+    fn trusted_owner_matches_bare_owner_and_owner_repo() {
+        let config = Config::from_str("trusted-owners:\n  - actions\n  - my-org/shared-workflows\n").unwrap();
+        assert!(config.is_trusted_owner("actions", "checkout"));
+        assert!(config.is_trusted_owner("my-org", "shared-workflows"));
+        assert!(!config.is_trusted_owner("my-org", "other-repo"));
+        assert!(!config.is_trusted_owner("some-other-owner", "checkout"));
+    }
+
+    #[test]
+    fn pinning_policy_falls_back_to_sha() {
+        let config = Config::from_str(
+            r#"
+pinning-policy:
+  - owner: my-org
+    require: tag
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.pin_requirement_for("my-org"), PinRequirement::Tag);
+        assert_eq!(config.pin_requirement_for("some-other-owner"), PinRequirement::Sha);
+    }
+
+    #[test]
+    fn severity_override_falls_back_to_default() {
+        let config = Config::from_str("severity-overrides:\n  unpinned-uses: high\n").unwrap();
+        assert_eq!(
+            config.effective_severity("unpinned-uses", Severity::Medium),
+            Severity::High
+        );
+        assert_eq!(
+            config.effective_severity("missing-timeout", Severity::Medium),
+            Severity::Medium
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Validation of a parsed [`Config`] beyond what `serde` rejects:
+//! unknown rule ids, malformed globs, and similarly "valid YAML, wrong
+//! content" mistakes that would otherwise fail silently.
+
+use crate::config::Config;
+use crate::registry::KNOWN_RULE_IDS;
+
+#[derive(Debug, Clone)]
+pub struct ConfigDiagnostic {
+    pub message: String,
+    /// Dotted path to the offending key, e.g. `ignore[0].rule`.
+    pub path: String,
+}
+
+impl std::fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Checks `config` for known-bad content, returning one diagnostic per
+/// problem found (so all issues can be reported at once, rather than
+/// failing on the first).
+pub fn validate(config: &Config) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = vec![];
+
+    for (idx, entry) in config.ignore.iter().enumerate() {
+        if !KNOWN_RULE_IDS.contains(&entry.rule.as_str()) && !config.custom_rules.iter().any(|r| r.id == entry.rule)
+        {
+            diagnostics.push(ConfigDiagnostic {
+                message: format!("unknown rule id `{}`", entry.rule),
+                path: format!("ignore[{idx}].rule"),
+            });
+        }
+        if let Some(glob) = &entry.workflow {
+            if glob::Pattern::new(glob).is_err() {
+                diagnostics.push(ConfigDiagnostic {
+                    message: format!("`{glob}` is not a valid glob pattern"),
+                    path: format!("ignore[{idx}].workflow"),
+                });
+            }
+        }
+        if config.policy.require_suppression_reason && entry.reason.as_deref().unwrap_or("").trim().is_empty() {
+            diagnostics.push(ConfigDiagnostic {
+                message: "suppression is missing a `reason:` (required by policy)".into(),
+                path: format!("ignore[{idx}].reason"),
+            });
+        }
+    }
+
+    for rule in config.severity_overrides.keys() {
+        if !KNOWN_RULE_IDS.contains(&rule.as_str()) {
+            diagnostics.push(ConfigDiagnostic {
+                message: format!("unknown rule id `{rule}`"),
+                path: format!("severity-overrides.{rule}"),
+            });
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_unknown_rule_and_bad_glob() {
+        let config = Config::from_str(
+            r#"
+ignore:
+  - rule: not-a-real-rule
+    workflow: "["
+"#,
+        )
+        .unwrap();
+        let diagnostics = validate(&config);
+        assert_eq!(diagnostics.len(), 2);
+    }
+
+    #[test]
+    fn known_rule_and_custom_rule_pass() {
+        let config = Config::from_str("ignore:\n  - rule: unpinned-uses\n").unwrap();
+        assert!(validate(&config).is_empty());
+    }
+}
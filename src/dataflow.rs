@@ -0,0 +1,269 @@
+//! Dataflow helpers shared by injection/exfiltration audits: how values
+//! move from `env:` into `run:` scripts, between steps via
+//! `steps.<id>.outputs.*`, and later (see the cross-job follow-up)
+//! between jobs.
+
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::models::Job;
+use crate::models::Step;
+use crate::taint::{taint_of, Taint};
+
+fn github_output_assignment_re() -> Regex {
+    // Matches the `echo "name=value" >> "$GITHUB_OUTPUT"` idiom for
+    // setting step outputs. Good enough to recover the output name and
+    // the expression assigned to it; heredoc (`<<EOF`) forms aren't
+    // handled yet.
+    Regex::new(r#"echo\s+"?([A-Za-z_][A-Za-z0-9_]*)=(.*?)"?\s*>>\s*"?\$GITHUB_OUTPUT"?"#).unwrap()
+}
+
+/// A `name=value` pair written to `$GITHUB_OUTPUT` by a step's `run:`
+/// script, i.e. a `steps.<id>.outputs.<name>` producer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepOutput {
+    pub name: String,
+    pub value: String,
+}
+
+/// Parses the `steps.<id>.outputs.*` values a step's `run:` script
+/// produces by writing to `$GITHUB_OUTPUT`.
+pub fn parse_step_outputs(run: &str) -> Vec<StepOutput> {
+    github_output_assignment_re()
+        .captures_iter(run)
+        .map(|c| StepOutput {
+            name: c[1].to_string(),
+            value: c[2].to_string(),
+        })
+        .collect()
+}
+
+fn expr_re() -> Regex {
+    Regex::new(r"\$\{\{\s*([^}]+?)\s*\}\}").unwrap()
+}
+
+/// The `(step_id, output_name)` pairs in `job` whose value was derived
+/// from an attacker-controlled expression under one of `workflow`'s
+/// triggers, i.e. outputs a consumer must not trust without re-checking.
+pub fn tainted_step_outputs(workflow: &crate::models::Workflow, job: &Job) -> HashSet<(String, String)> {
+    let triggers = crate::models::trigger_names(&workflow.on);
+    let mut tainted = HashSet::new();
+
+    for step in &job.steps {
+        let Some(id) = &step.id else { continue };
+        let Some(run) = &step.run else { continue };
+        for output in parse_step_outputs(run) {
+            let is_tainted = expr_re().captures_iter(&output.value).any(|c| {
+                triggers.iter().any(|t| taint_of(t, &c[1]) == Taint::AttackerControlled)
+            });
+            if is_tainted {
+                tainted.insert((id.clone(), output.name));
+            }
+        }
+    }
+
+    tainted
+}
+
+/// Parses a `steps.<id>.outputs.<name>` reference out of an expression,
+/// if it's one.
+pub fn parse_step_output_ref(expr: &str) -> Option<(String, String)> {
+    let re = Regex::new(r"^steps\.([A-Za-z_][A-Za-z0-9_-]*)\.outputs\.([A-Za-z_][A-Za-z0-9_-]*)$").unwrap();
+    let captures = re.captures(expr.trim())?;
+    Some((captures[1].to_string(), captures[2].to_string()))
+}
+
+/// Parses a `needs.<job>.outputs.<name>` reference out of an expression,
+/// if it's one.
+pub fn parse_job_output_ref(expr: &str) -> Option<(String, String)> {
+    let re = Regex::new(r"^needs\.([A-Za-z_][A-Za-z0-9_-]*)\.outputs\.([A-Za-z_][A-Za-z0-9_-]*)$").unwrap();
+    let captures = re.captures(expr.trim())?;
+    Some((captures[1].to_string(), captures[2].to_string()))
+}
+
+/// The output names of `job_id` whose value was derived from an
+/// attacker-controlled expression, either directly (`on:`-trigger
+/// context) or indirectly through a tainted `steps.<id>.outputs.*`
+/// reference. `job_id`'s own `outputs:` block is resolved against its own
+/// steps, not the whole workflow, matching how GitHub Actions scopes it.
+pub fn tainted_job_outputs(workflow: &crate::models::Workflow, job_id: &str) -> HashSet<String> {
+    let Some(job) = workflow.jobs.get(job_id) else {
+        return HashSet::new();
+    };
+    let triggers = crate::models::trigger_names(&workflow.on);
+    let step_outputs = tainted_step_outputs(workflow, job);
+
+    job.outputs
+        .iter()
+        .filter(|(_, value)| {
+            expr_re().captures_iter(value).any(|c| {
+                let expr = &c[1];
+                triggers.iter().any(|t| taint_of(t, expr) == Taint::AttackerControlled)
+                    || parse_step_output_ref(expr).is_some_and(|pair| step_outputs.contains(&pair))
+            })
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+fn shell_var_re(name: &str) -> Regex {
+    // Matches `$NAME`, `${NAME}`, and `${NAME:-default}` style expansions.
+    Regex::new(&format!(r"\$\{{?{}\b", regex::escape(name))).unwrap()
+}
+
+/// Returns the names of env vars visible to `step` - layered from
+/// `workflow`, `job`, and `step` `env:` blocks via
+/// [`crate::env_resolution::effective_env`] - that its `run:` script
+/// actually expands unquoted-shell-style, i.e. the env-hop is live
+/// rather than merely declared-but-unused.
+pub fn env_vars_reaching_run(workflow: &crate::models::Workflow, job: &Job, step: &Step) -> Vec<String> {
+    let Some(run) = &step.run else { return vec![] };
+
+    crate::env_resolution::effective_env(workflow, job, step)
+        .into_keys()
+        .filter(|name| shell_var_re(name).is_match(run))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+
+    fn step_with_run(run: &str) -> Step {
+        Step {
+            id: None,
+            name: None,
+            uses: None,
+            shell: None,
+            run: Some(run.to_string()),
+            with: IndexMap::new(),
+            env: IndexMap::new(),
+            if_: None,
+            working_directory: None,
+        }
+    }
+
+    #[test]
+    fn flags_only_env_vars_actually_expanded_in_run() {
+        let mut job_env = IndexMap::new();
+        job_env.insert("TITLE".to_string(), "${{ github.event.pull_request.title }}".to_string());
+        job_env.insert("UNUSED".to_string(), "safe".to_string());
+        let job = Job {
+            runs_on: None,
+            permissions: None,
+            steps: vec![],
+            needs: vec![],
+            env: job_env,
+            timeout_minutes: None,
+            outputs: IndexMap::new(),
+            strategy: None,
+            uses: None,
+            with: IndexMap::new(),
+            secrets: None,
+            if_: None,
+            defaults: None,
+            concurrency: None,
+        };
+        let step = step_with_run("echo \"$TITLE\"");
+        let workflow = crate::models::Workflow::from_string("w.yml", "on: push\njobs:\n  j:\n    steps: []\n").unwrap();
+
+        let reaching = env_vars_reaching_run(&workflow, &job, &step);
+        assert_eq!(reaching, vec!["TITLE".to_string()]);
+    }
+
+    #[test]
+    fn taints_step_output_derived_from_attacker_controlled_expression() {
+        let producer = Step {
+            id: Some("get-title".to_string()),
+            name: None,
+            uses: None,
+            shell: None,
+            run: Some(r#"echo "title=${{ github.event.pull_request.title }}" >> "$GITHUB_OUTPUT""#.to_string()),
+            with: IndexMap::new(),
+            env: IndexMap::new(),
+            if_: None,
+            working_directory: None,
+        };
+        let job = Job {
+            runs_on: None,
+            permissions: None,
+            steps: vec![producer],
+            needs: vec![],
+            env: IndexMap::new(),
+            timeout_minutes: None,
+            outputs: IndexMap::new(),
+            strategy: None,
+            uses: None,
+            with: IndexMap::new(),
+            secrets: None,
+            if_: None,
+            defaults: None,
+            concurrency: None,
+        };
+        let workflow =
+            crate::models::Workflow::from_string("w.yml", "on: pull_request_target\njobs:\n  j:\n    steps: []\n").unwrap();
+
+        let tainted = tainted_step_outputs(&workflow, &job);
+        assert!(tainted.contains(&("get-title".to_string(), "title".to_string())));
+    }
+
+    #[test]
+    fn parses_step_output_reference() {
+        assert_eq!(
+            parse_step_output_ref("steps.get-title.outputs.title"),
+            Some(("get-title".to_string(), "title".to_string()))
+        );
+        assert_eq!(parse_step_output_ref("github.event.pull_request.title"), None);
+    }
+
+    #[test]
+    fn taints_job_output_derived_from_tainted_step_output() {
+        let producer = Step {
+            id: Some("get-title".to_string()),
+            name: None,
+            uses: None,
+            shell: None,
+            run: Some(r#"echo "title=${{ github.event.pull_request.title }}" >> "$GITHUB_OUTPUT""#.to_string()),
+            with: IndexMap::new(),
+            env: IndexMap::new(),
+            if_: None,
+            working_directory: None,
+        };
+        let mut job_outputs = IndexMap::new();
+        job_outputs.insert("title".to_string(), "${{ steps.get-title.outputs.title }}".to_string());
+        let job = Job {
+            runs_on: None,
+            permissions: None,
+            steps: vec![producer],
+            needs: vec![],
+            env: IndexMap::new(),
+            timeout_minutes: None,
+            outputs: job_outputs,
+            strategy: None,
+            uses: None,
+            with: IndexMap::new(),
+            secrets: None,
+            if_: None,
+            defaults: None,
+            concurrency: None,
+        };
+        let mut jobs = IndexMap::new();
+        jobs.insert("produce".to_string(), job);
+        let workflow = crate::models::Workflow {
+            name: None,
+            on: serde_yaml::from_str("pull_request_target").unwrap(),
+            permissions: None,
+            env: IndexMap::new(),
+            defaults: None,
+            concurrency: None,
+            jobs,
+            path: "w.yml".into(),
+            raw: String::new(),
+        };
+
+        let tainted = tainted_job_outputs(&workflow, "produce");
+        assert!(tainted.contains("title"));
+    }
+}
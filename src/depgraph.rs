@@ -0,0 +1,113 @@
+//! Cross-workflow trust graph: which workflow pulls in which action,
+//! reusable workflow, or container image (reusing [`crate::sbom`]'s
+//! classification), plus which workflow chains off another via
+//! `workflow_run`. Infrastructure for `zizmor graph`'s Graphviz/JSON
+//! export - unlike [`crate::graph::JobGraph`], which only covers a
+//! single workflow's intra-job `needs:` edges.
+
+use serde::Serialize;
+
+use crate::models::Workflow;
+use crate::sbom::{self, ComponentKind};
+use crate::triggers::Triggers;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EdgeKind {
+    Uses,
+    ReusableWorkflowCall,
+    WorkflowRun,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub kind: EdgeKind,
+}
+
+fn workflow_run_names(filter: &serde_yaml::Value) -> Vec<String> {
+    filter
+        .get("workflows")
+        .and_then(serde_yaml::Value::as_sequence)
+        .map(|seq| seq.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+/// Builds the dependency graph across `workflows`: one edge per
+/// `uses:`/reusable-workflow-call component each workflow references,
+/// plus one edge per `workflow_run` trigger naming another workflow
+/// file.
+pub fn build(workflows: &[Workflow]) -> Vec<Edge> {
+    let mut edges = vec![];
+
+    for workflow in workflows {
+        let name = workflow.path.file_name().unwrap_or(workflow.path.as_str()).to_string();
+
+        for component in sbom::components(workflow) {
+            let kind = match component.kind {
+                ComponentKind::ReusableWorkflow => EdgeKind::ReusableWorkflowCall,
+                ComponentKind::Action | ComponentKind::ContainerImage => EdgeKind::Uses,
+            };
+            edges.push(Edge { from: name.clone(), to: component.name, kind });
+        }
+
+        let triggers = Triggers::new(&workflow.on);
+        if let Some(filter) = triggers.filters_for("workflow_run") {
+            for called in workflow_run_names(filter) {
+                edges.push(Edge { from: name.clone(), to: called, kind: EdgeKind::WorkflowRun });
+            }
+        }
+    }
+
+    edges
+}
+
+/// Renders `edges` as a Graphviz `digraph`.
+pub fn to_dot(edges: &[Edge]) -> String {
+    let mut out = String::from("digraph workflows {\n");
+    for edge in edges {
+        let style = match edge.kind {
+            EdgeKind::Uses => "solid",
+            EdgeKind::ReusableWorkflowCall => "bold",
+            EdgeKind::WorkflowRun => "dashed",
+        };
+        out.push_str(&format!("  {:?} -> {:?} [style={style}];\n", edge.from, edge.to));
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_uses_edge() {
+        let workflow = Workflow::from_string(
+            "ci.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n",
+        )
+        .unwrap();
+        let edges = build(&[workflow]);
+        assert_eq!(edges, vec![Edge { from: "ci.yml".into(), to: "actions/checkout".into(), kind: EdgeKind::Uses }]);
+    }
+
+    #[test]
+    fn builds_workflow_run_edge() {
+        let workflow = Workflow::from_string(
+            "deploy.yml",
+            "on:\n  workflow_run:\n    workflows: [\"ci.yml\"]\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        )
+        .unwrap();
+        let edges = build(&[workflow]);
+        assert_eq!(edges, vec![Edge { from: "deploy.yml".into(), to: "ci.yml".into(), kind: EdgeKind::WorkflowRun }]);
+    }
+
+    #[test]
+    fn to_dot_renders_quoted_node_names() {
+        let edges = vec![Edge { from: "ci.yml".into(), to: "actions/checkout".into(), kind: EdgeKind::Uses }];
+        let dot = to_dot(&edges);
+        assert!(dot.contains("\"ci.yml\" -> \"actions/checkout\" [style=solid];"));
+    }
+}
@@ -0,0 +1,246 @@
+//! Embedded rule documentation for `zizmor explain`, so CI logs can
+//! explain a finding without a network round-trip to a docs site.
+
+/// A rule's offline documentation entry.
+#[derive(Debug, Clone, Copy)]
+pub struct RuleDoc {
+    pub ident: &'static str,
+    pub title: &'static str,
+    pub default_severity: &'static str,
+    pub rationale: &'static str,
+    pub example: &'static str,
+    pub remediation: &'static str,
+}
+
+macro_rules! doc {
+    ($ident:expr, $title:expr, $severity:expr, $rationale:expr, $example:expr, $remediation:expr) => {
+        RuleDoc {
+            ident: $ident,
+            title: $title,
+            default_severity: $severity,
+            rationale: $rationale,
+            example: $example,
+            remediation: $remediation,
+        }
+    };
+}
+
+const RULE_DOCS: &[RuleDoc] = &[
+    doc!(
+        "cache-poisoning",
+        "Cache poisoning risk in a privileged job",
+        "medium",
+        "A job with elevated permissions (or a release/publish job) that restores a cache can be fed a poisoned entry written by a less-privileged, fork-triggered run - especially via `restore-keys` prefix matching.",
+        "permissions:\n  contents: write\njobs:\n  release:\n    steps:\n      - uses: actions/cache@<sha>\n        with:\n          restore-keys: |\n            build-",
+        "Scope cache keys to something a fork PR can't control (e.g. the base branch/ref), or don't restore caches written by less-privileged runs in this job."
+    ),
+    doc!(
+        "external-secrets-inherit",
+        "secrets: inherit into an external reusable workflow",
+        "high",
+        "`secrets: inherit` on a call to a reusable workflow outside the calling repository/owner hands that third-party code every secret available in the caller's context, not just the ones it actually needs.",
+        "jobs:\n  call:\n    uses: some-org/some-repo/.github/workflows/build.yml@<sha>\n    secrets: inherit",
+        "Pass only the specific secrets the callee declares instead of `inherit`, or add the owner to `trusted_owners` if it's genuinely first-party."
+    ),
+    doc!(
+        "missing-permissions",
+        "No permissions declared",
+        "low",
+        "A job with no `permissions:` block anywhere (workflow or job level) runs with whatever default `GITHUB_TOKEN` permissions the repository or org has configured, which isn't visible from the workflow file itself.",
+        "on: pull_request_target\njobs:\n  build:\n    runs-on: ubuntu-latest\n    steps: []",
+        "Add an explicit `permissions:` block (workflow- or job-level) with the minimum scopes the job needs."
+    ),
+    doc!(
+        "unpinned-uses",
+        "Unpinned action reference",
+        "medium",
+        "A `uses:` pinned to a tag or branch can change underneath you; whoever controls that ref controls what runs in your CI with your secrets.",
+        "- uses: actions/checkout@v4",
+        "Pin to the full 40-character commit SHA: `- uses: actions/checkout@<sha> # v4`."
+    ),
+    doc!(
+        "excessive-permissions",
+        "Excessive workflow permissions",
+        "medium",
+        "The default `GITHUB_TOKEN` permissions (or an explicit `permissions: write-all`) give every job more access than most jobs need.",
+        "permissions: write-all",
+        "Declare the minimum permissions each job actually needs, e.g. `permissions:\n  contents: read`."
+    ),
+    doc!(
+        "dangerous-triggers",
+        "Dangerous trigger",
+        "high",
+        "`pull_request_target` and similar triggers run with base-repo privileges against untrusted fork content.",
+        "on: pull_request_target",
+        "Avoid checking out or executing untrusted fork content under this trigger, or switch to `pull_request` with read-only permissions."
+    ),
+    doc!(
+        "missing-timeout",
+        "Missing job timeout",
+        "low",
+        "A job with no `timeout-minutes` can run (and consume billed minutes) indefinitely if a step hangs.",
+        "jobs:\n  build:\n    runs-on: ubuntu-latest\n    steps: []",
+        "Add an explicit `timeout-minutes:` to the job."
+    ),
+    doc!(
+        "deprecated-commands",
+        "Deprecated workflow command",
+        "medium",
+        "`::set-output`/`::save-state`/`::add-path` are deprecated in favor of `$GITHUB_OUTPUT`/`$GITHUB_STATE`/`$GITHUB_PATH` and may stop working.",
+        "run: echo \"::set-output name=x::1\"",
+        "Write to the corresponding environment file instead, e.g. `echo \"x=1\" >> \"$GITHUB_OUTPUT\"`."
+    ),
+    doc!(
+        "deprecated-runner-image",
+        "Deprecated runner image",
+        "low",
+        "Older `runs-on` images lose security updates and are eventually removed by GitHub.",
+        "runs-on: ubuntu-18.04",
+        "Move to a currently-supported runner label, e.g. `ubuntu-latest`."
+    ),
+    doc!(
+        "checkout-persist-credentials",
+        "Checkout leaves credentials persisted",
+        "medium",
+        "`actions/checkout` persists the `GITHUB_TOKEN` in the local git config by default, which later steps (including third-party actions) can read.",
+        "- uses: actions/checkout@<sha>",
+        "Set `persist-credentials: false` unless a later step needs to push/authenticate as the token."
+    ),
+    doc!(
+        "template-injection",
+        "Template injection",
+        "high",
+        "Interpolating an untrusted `${{ }}` expression directly into a `run:` script lets its value break out into shell syntax.",
+        "run: echo \"${{ github.event.issue.title }}\"",
+        "Pass the value through an `env:` variable and reference the environment variable from the shell instead of interpolating the expression directly."
+    ),
+    doc!(
+        "job-dependency-graph",
+        "Invalid job dependency graph",
+        "medium",
+        "A `needs:` entry naming an undefined job, or a cycle between jobs, can never produce a valid run.",
+        "jobs:\n  b:\n    needs: [a]",
+        "Fix the `needs:` reference, or remove the cycle."
+    ),
+    doc!(
+        "reusable-workflow-call",
+        "Reusable workflow call binding",
+        "medium",
+        "A reusable-workflow call's `with:`/`secrets:` must match the callee's declared `inputs:`/`secrets:`.",
+        "jobs:\n  call:\n    uses: org/repo/.github/workflows/build.yml@<sha>\n    with:\n      typo-input: 1",
+        "Match the `with:`/`secrets:` keys to what the callee actually declares."
+    ),
+    doc!(
+        "unreachable-code",
+        "Unreachable step",
+        "low",
+        "A step whose `if:` condition can never be true (e.g. it contradicts the job's own `if:`) never runs.",
+        "if: false",
+        "Remove the step, or fix the condition."
+    ),
+    doc!(
+        "invalid-event-context",
+        "Invalid event context reference",
+        "medium",
+        "An expression references an `event.*` field that doesn't exist for the workflow's declared trigger.",
+        "on: push\n...\nrun: echo ${{ github.event.pull_request.number }}",
+        "Reference a field the trigger's event payload actually has, or guard the step with an `if:` for the right event."
+    ),
+    doc!(
+        "overbroad-concurrency",
+        "Overbroad concurrency group",
+        "low",
+        "A `concurrency.group` that doesn't include a ref/branch-scoped key can cancel or queue unrelated runs against each other.",
+        "concurrency:\n  group: build",
+        "Scope the group key to the ref, e.g. `group: build-${{ github.ref }}`."
+    ),
+    doc!(
+        "secret-in-logs",
+        "Secret may be printed to logs",
+        "high",
+        "A `run:`/`echo` step that interpolates a `secrets.*` value directly can print it to the build log even though GitHub's log masking isn't perfectly reliable.",
+        "run: echo ${{ secrets.TOKEN }}",
+        "Avoid echoing secrets; if a step genuinely needs to use one, pass it via `env:` and let the tool read the environment variable."
+    ),
+    doc!(
+        "invalid-step-reference",
+        "Invalid step output reference",
+        "medium",
+        "A `steps.<id>.outputs.*` reference names a step id that isn't defined (or isn't defined yet) in the job.",
+        "run: echo ${{ steps.missing.outputs.value }}",
+        "Fix the step id, or give the producing step an explicit `id:`."
+    ),
+    doc!(
+        "env-reference",
+        "Env reference can never resolve",
+        "medium",
+        "An `env:` entry that references another `env:` name defined later in the same scope (or cycles back to itself) can never resolve, since GitHub evaluates `env:` top-to-bottom.",
+        "env:\n  A: ${{ env.B }}\n  B: 1",
+        "Reorder the `env:` entries so each only references ones defined earlier."
+    ),
+    doc!(
+        "pin-comment-mismatch",
+        "Pin comment doesn't look like a version",
+        "informational",
+        "A SHA-pinned `uses:` with a trailing comment that isn't actually a version tag is misleading to anyone skimming the diff for what version is pinned.",
+        "- uses: actions/checkout@<sha> # do not touch",
+        "Update the comment to the version tag the SHA corresponds to, e.g. `# v4.1.1`."
+    ),
+    doc!(
+        "known-vulnerable-action",
+        "Known-vulnerable or malicious action",
+        "high",
+        "This `uses:` reference matches an advisory in the configured OSV feed for a known-vulnerable or malicious release.",
+        "- uses: evil/action@<sha>",
+        "Pin to a release that predates (or postdates a fix for) the advisory, or drop the dependency."
+    ),
+    doc!(
+        "pull-request-target-checkout",
+        "Untrusted checkout under pull_request_target",
+        "high",
+        "A pull_request_target workflow that checks out the PR head and then runs a later step hands an attacker-controlled fork's code your elevated token and secrets - the classic pwn-request pattern.",
+        "on: pull_request_target\njobs:\n  build:\n    steps:\n      - uses: actions/checkout@v4\n        with:\n          ref: ${{ github.event.pull_request.head.sha }}\n      - run: npm test",
+        "Switch to pull_request (no secrets on fork PRs), or split the privileged step into a separate workflow_run-triggered job that never checks out untrusted code."
+    ),
+    doc!(
+        "secrets-to-unpinned-uses",
+        "Secret passed to an unpinned action",
+        "high",
+        "A `with:`/`env:` value derived from `secrets.*` is handed to a third-party action pinned only to a mutable branch or tag; whoever controls that ref controls code that runs with the secret.",
+        "- uses: some-org/some-action@v1\n  with:\n    token: ${{ secrets.GH_TOKEN }}",
+        "Pin the action to a commit SHA, or add the owner to `trusted_owners` if it's genuinely first-party."
+    ),
+    doc!(
+        "workflow-run-artifact-poisoning",
+        "Untrusted artifact under workflow_run",
+        "high",
+        "A workflow_run job that downloads the triggering run's artifacts and then runs a later step is acting on attacker-controlled content if the triggering run came from a fork PR.",
+        "on: workflow_run\njobs:\n  publish:\n    steps:\n      - uses: actions/download-artifact@v4\n      - run: ./dist/run.sh",
+        "Validate or re-build the artifact's contents yourself rather than trusting the download, or avoid running anything derived from it in this privileged context."
+    ),
+];
+
+/// Looks up the embedded documentation for `ident`, or `None` if it
+/// isn't a known built-in rule (e.g. a custom/script rule, which has no
+/// embedded doc to fall back on).
+pub fn lookup(ident: &str) -> Option<&'static RuleDoc> {
+    RULE_DOCS.iter().find(|doc| doc.ident == ident)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::KNOWN_RULE_IDS;
+
+    #[test]
+    fn every_known_rule_has_a_doc_entry() {
+        for ident in KNOWN_RULE_IDS {
+            assert!(lookup(ident).is_some(), "missing doc entry for {ident}");
+        }
+    }
+
+    #[test]
+    fn unknown_rule_returns_none() {
+        assert!(lookup("not-a-real-rule").is_none());
+    }
+}
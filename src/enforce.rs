@@ -0,0 +1,129 @@
+//! `--enforce-policy`: a hard organizational gate mode for central
+//! security teams, where scanned repos can't weaken a central config.
+
+use crate::config::{Config, PinRequirement};
+use crate::finding::Severity;
+
+/// Ranks a [`PinRequirement`] from strictest to most permissive, so two
+/// requirements for the same owner can be compared for "did the repo
+/// loosen this".
+fn strictness(requirement: PinRequirement) -> u8 {
+    match requirement {
+        PinRequirement::Sha => 2,
+        PinRequirement::Tag => 1,
+        PinRequirement::Any => 0,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyViolation {
+    pub message: String,
+}
+
+/// Checks `repo_config` (a per-repository overlay, see
+/// [`crate::org_scan`]) against `central` for attempts to weaken policy.
+/// Under `--enforce-policy`, any non-empty result should be treated as a
+/// hard failure rather than merged in.
+pub fn check(central: &Config, repo_config: &Config) -> Vec<PolicyViolation> {
+    let mut violations = vec![];
+
+    if !repo_config.ignore.is_empty() {
+        violations.push(PolicyViolation {
+            message: "repo config declares inline/local suppressions, which --enforce-policy forbids".into(),
+        });
+    }
+
+    for (rule, severity) in &repo_config.severity_overrides {
+        let central_severity = central.effective_severity(rule, Severity::Unknown);
+        if *severity < central_severity {
+            violations.push(PolicyViolation {
+                message: format!(
+                    "repo config downgrades `{rule}` from {central_severity} to {severity}, which --enforce-policy forbids"
+                ),
+            });
+        }
+    }
+
+    for owner in &repo_config.trusted_owners {
+        let (owner_part, repo_part) = owner.split_once('/').unwrap_or((owner.as_str(), ""));
+        if !central.is_trusted_owner(owner_part, repo_part) {
+            violations.push(PolicyViolation {
+                message: format!(
+                    "repo config adds `{owner}` to trusted_owners, widening central policy, which --enforce-policy forbids"
+                ),
+            });
+        }
+    }
+
+    for rule in &repo_config.pinning_policy {
+        let central_requirement = central.pin_requirement_for(&rule.owner);
+        if strictness(rule.require) < strictness(central_requirement) {
+            violations.push(PolicyViolation {
+                message: format!(
+                    "repo config relaxes the pinning policy for `{}` from {central_requirement:?} to {:?}, which --enforce-policy forbids",
+                    rule.owner, rule.require
+                ),
+            });
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_severity_downgrade_and_local_suppressions() {
+        let central = Config::from_str("severity-overrides:\n  unpinned-uses: high\n").unwrap();
+        let repo = Config::from_str(
+            r#"
+ignore:
+  - rule: unpinned-uses
+severity-overrides:
+  unpinned-uses: low
+"#,
+        )
+        .unwrap();
+
+        let violations = check(&central, &repo);
+        assert_eq!(violations.len(), 2);
+    }
+
+    #[test]
+    fn flags_widened_trusted_owners() {
+        let central = Config::from_str("trusted-owners:\n  - actions\n").unwrap();
+        let repo = Config::from_str("trusted-owners:\n  - some-fork-owner\n").unwrap();
+
+        let violations = check(&central, &repo);
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn allows_trusted_owner_already_covered_by_central() {
+        let central = Config::from_str("trusted-owners:\n  - actions\n").unwrap();
+        let repo = Config::from_str("trusted-owners:\n  - actions\n").unwrap();
+
+        let violations = check(&central, &repo);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn flags_relaxed_pinning_policy() {
+        let central = Config::from_str("pinning-policy:\n  - owner: my-org\n    require: sha\n").unwrap();
+        let repo = Config::from_str("pinning-policy:\n  - owner: my-org\n    require: any\n").unwrap();
+
+        let violations = check(&central, &repo);
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn allows_stricter_or_equal_pinning_policy() {
+        let central = Config::from_str("pinning-policy:\n  - owner: my-org\n    require: tag\n").unwrap();
+        let repo = Config::from_str("pinning-policy:\n  - owner: my-org\n    require: sha\n").unwrap();
+
+        let violations = check(&central, &repo);
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! `ZIZMOR_*` environment variables mirroring CLI flags, for
+//! containerized CI invocations that can't easily edit command lines.
+
+use crate::cli::Args;
+
+
+/// Applies any set `ZIZMOR_*` variables onto `args`, without overriding
+/// flags the user passed explicitly on the command line.
+pub fn apply(args: &mut Args) {
+    if !args.offline {
+        if let Ok(val) = std::env::var("ZIZMOR_OFFLINE") {
+            args.offline = matches!(val.as_str(), "1" | "true" | "yes");
+        }
+    }
+    if args.gh_token.is_none() {
+        if let Ok(val) = std::env::var("ZIZMOR_GH_TOKEN") {
+            args.gh_token = Some(val);
+        }
+    }
+    if let Ok(val) = std::env::var("ZIZMOR_CONFIG") {
+        if args.config.is_none() {
+            args.config = Some(val.into());
+        }
+    }
+}
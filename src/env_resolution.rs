@@ -0,0 +1,180 @@
+//! Resolves the effective `env:` for a step by layering workflow-, job-,
+//! and step-level `env:` maps in GitHub's own precedence order (step
+//! overrides job overrides workflow), so audits that need a step's
+//! actual environment - secret exposure, exfiltration sinks, `set -x`
+//! leaking values to logs - share one implementation instead of each
+//! re-deriving the layering themselves.
+//!
+//! Values are returned exactly as written, including unevaluated
+//! `${{ ... }}` expressions: this module only resolves *which* value
+//! wins for a given name, not what an expression evaluates to.
+
+use indexmap::IndexMap;
+use regex::Regex;
+
+use crate::models::{Job, Step, Workflow};
+
+/// The effective value of every env var visible to `step`, after
+/// applying workflow/job/step precedence. Expression values (`${{ ... }}`)
+/// are left as-is for the caller to interpret.
+pub fn effective_env(workflow: &Workflow, job: &Job, step: &Step) -> IndexMap<String, String> {
+    let mut env = workflow.env.clone();
+    env.extend(job.env.iter().map(|(k, v)| (k.clone(), v.clone())));
+    for (name, value) in &step.env {
+        env.insert(name.clone(), value.clone());
+    }
+    env
+}
+
+/// A problem found while resolving `${{ env.X }}` references within a
+/// single `env:` mapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvRefIssue {
+    /// `name` reads `refers_to`, which is declared later in the same
+    /// mapping - GitHub evaluates `env:` top-to-bottom, so `refers_to`
+    /// isn't populated yet when `name` is resolved.
+    ForwardReference { name: String, refers_to: String },
+    /// `name`'s value, directly or transitively through other names in
+    /// the same mapping, reads back to `name` itself - unresolvable no
+    /// matter what order GitHub evaluates the mapping in.
+    Cycle { name: String },
+}
+
+fn expr_re() -> Regex {
+    Regex::new(r"\$\{\{\s*([^}]+?)\s*\}\}").unwrap()
+}
+
+/// The names of env vars that `value` reads via `${{ env.NAME }}`.
+fn env_refs(value: &str) -> Vec<String> {
+    expr_re()
+        .captures_iter(value)
+        .filter_map(|c| c[1].trim().strip_prefix("env.").map(str::to_string))
+        .collect()
+}
+
+/// Whether following `env.X` references from `start`, one hop at a
+/// time, leads back to `start` - a definition cycle, regardless of
+/// declaration order.
+fn cycle_contains(env: &IndexMap<String, String>, start: &str) -> bool {
+    let mut current = start.to_string();
+    for _ in 0..=env.len() {
+        let Some(value) = env.get(&current) else { return false };
+        let Some(next) = env_refs(value).into_iter().next() else { return false };
+        if next == start {
+            return true;
+        }
+        current = next;
+    }
+    false
+}
+
+/// Finds forward references and definition cycles in a single `env:`
+/// mapping (a workflow's, a job's, or a step's own - not the layered
+/// result of [`effective_env`], since this rule is about one mapping's
+/// internal declaration order).
+pub fn self_reference_issues(env: &IndexMap<String, String>) -> Vec<EnvRefIssue> {
+    let mut issues = vec![];
+
+    for (i, (name, value)) in env.iter().enumerate() {
+        for referenced in env_refs(value) {
+            if referenced == *name || cycle_contains(env, name) {
+                issues.push(EnvRefIssue::Cycle { name: name.clone() });
+                break;
+            }
+            if let Some(ref_idx) = env.get_index_of(&referenced) {
+                if ref_idx > i {
+                    issues.push(EnvRefIssue::ForwardReference {
+                        name: name.clone(),
+                        refers_to: referenced,
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step_with_env(pairs: &[(&str, &str)]) -> Step {
+        let mut env = IndexMap::new();
+        for (k, v) in pairs {
+            env.insert(k.to_string(), v.to_string());
+        }
+        Step {
+            id: None,
+            name: None,
+            uses: None,
+            run: None,
+            shell: None,
+            with: IndexMap::new(),
+            env,
+            if_: None,
+            working_directory: None,
+        }
+    }
+
+    #[test]
+    fn step_env_overrides_job_and_workflow_env() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\nenv:\n  NAME: workflow\n  ONLY_WORKFLOW: w\njobs:\n  j:\n    runs-on: ubuntu-latest\n    env:\n      NAME: job\n    steps: []\n",
+        )
+        .unwrap();
+        let job = workflow.jobs.get("j").unwrap();
+        let step = step_with_env(&[("NAME", "step")]);
+
+        let env = effective_env(&workflow, job, &step);
+        assert_eq!(env.get("NAME").map(String::as_str), Some("step"));
+        assert_eq!(env.get("ONLY_WORKFLOW").map(String::as_str), Some("w"));
+    }
+
+    #[test]
+    fn falls_back_to_job_env_when_step_has_none() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    env:\n      NAME: job\n    steps: []\n",
+        )
+        .unwrap();
+        let job = workflow.jobs.get("j").unwrap();
+        let step = step_with_env(&[]);
+
+        let env = effective_env(&workflow, job, &step);
+        assert_eq!(env.get("NAME").map(String::as_str), Some("job"));
+    }
+
+    #[test]
+    fn allows_reference_to_earlier_env_var() {
+        let mut env = IndexMap::new();
+        env.insert("BASE".to_string(), "v1".to_string());
+        env.insert("FULL".to_string(), "${{ env.BASE }}-full".to_string());
+        assert_eq!(self_reference_issues(&env), vec![]);
+    }
+
+    #[test]
+    fn flags_forward_reference() {
+        let mut env = IndexMap::new();
+        env.insert("FULL".to_string(), "${{ env.BASE }}-full".to_string());
+        env.insert("BASE".to_string(), "v1".to_string());
+        assert_eq!(
+            self_reference_issues(&env),
+            vec![EnvRefIssue::ForwardReference {
+                name: "FULL".to_string(),
+                refers_to: "BASE".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn flags_definition_cycle() {
+        let mut env = IndexMap::new();
+        env.insert("A".to_string(), "${{ env.B }}".to_string());
+        env.insert("B".to_string(), "${{ env.A }}".to_string());
+        let issues = self_reference_issues(&env);
+        assert!(issues.contains(&EnvRefIssue::Cycle { name: "A".to_string() }));
+        assert!(issues.contains(&EnvRefIssue::Cycle { name: "B".to_string() }));
+    }
+}
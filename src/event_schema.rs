@@ -0,0 +1,95 @@
+//! Knowledge base of which top-level `github.event.*` namespaces are
+//! actually populated under each trigger, so audits can tell a
+//! valid-but-dangerous context access (e.g. `github.event.issue.body` on
+//! `issue_comment`) from a typo or copy-paste leftover (e.g.
+//! `github.event.pull_request.body` on a `push` trigger, which is always
+//! empty there). Deliberately coarse - it reasons about the first path
+//! segment after `github.event.`, not the full field tree, since
+//! GitHub's payload schemas are too deep to enumerate exhaustively here.
+
+/// Namespaces present under every trigger regardless of event type, so
+/// they never produce a false positive.
+const COMMON_NAMESPACES: &[&str] = &["repository", "sender", "organization", "installation"];
+
+struct Entry {
+    trigger: &'static str,
+    namespace: &'static str,
+}
+
+const TABLE: &[Entry] = &[
+    Entry { trigger: "pull_request", namespace: "pull_request" },
+    Entry { trigger: "pull_request_target", namespace: "pull_request" },
+    Entry { trigger: "issues", namespace: "issue" },
+    Entry { trigger: "issue_comment", namespace: "issue" },
+    Entry { trigger: "issue_comment", namespace: "comment" },
+    Entry { trigger: "push", namespace: "head_commit" },
+    Entry { trigger: "push", namespace: "commits" },
+    Entry { trigger: "push", namespace: "pusher" },
+    Entry { trigger: "release", namespace: "release" },
+    Entry { trigger: "workflow_run", namespace: "workflow_run" },
+    Entry { trigger: "workflow_dispatch", namespace: "inputs" },
+    Entry { trigger: "workflow_call", namespace: "inputs" },
+    Entry { trigger: "discussion", namespace: "discussion" },
+    Entry { trigger: "discussion_comment", namespace: "discussion" },
+    Entry { trigger: "discussion_comment", namespace: "comment" },
+];
+
+/// Extracts the first path segment after `github.event.`, e.g.
+/// `github.event.pull_request.title` -> `Some("pull_request")`. Returns
+/// `None` for paths that aren't a `github.event.*` access at all.
+fn event_namespace(path: &str) -> Option<&str> {
+    path.strip_prefix("github.event.")?.split('.').next()
+}
+
+fn namespace_known_for_trigger(trigger: &str, namespace: &str) -> bool {
+    TABLE.iter().any(|e| e.trigger == trigger && e.namespace == namespace)
+}
+
+/// Whether `path` (a dotted context access) is consistent with at least
+/// one of the workflow's `triggers`. Non-`github.event.*` paths and
+/// namespaces this knowledge base doesn't model are always considered
+/// valid, so the audit built on this only flags namespaces it actually
+/// knows are absent - erring towards missing typos rather than
+/// flagging legitimate but unlisted fields.
+pub fn is_valid_event_field(triggers: &[String], path: &str) -> bool {
+    let Some(namespace) = event_namespace(path) else {
+        return true;
+    };
+    if COMMON_NAMESPACES.contains(&namespace) {
+        return true;
+    }
+    let modeled = TABLE.iter().any(|e| e.namespace == namespace);
+    if !modeled {
+        return true;
+    }
+    triggers.iter().any(|trigger| namespace_known_for_trigger(trigger, namespace))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pr_body_is_valid_on_pull_request_target() {
+        let triggers = vec!["pull_request_target".to_string()];
+        assert!(is_valid_event_field(&triggers, "github.event.pull_request.body"));
+    }
+
+    #[test]
+    fn pr_body_is_invalid_on_push() {
+        let triggers = vec!["push".to_string()];
+        assert!(!is_valid_event_field(&triggers, "github.event.pull_request.body"));
+    }
+
+    #[test]
+    fn unmodeled_namespace_is_never_flagged() {
+        let triggers = vec!["push".to_string()];
+        assert!(is_valid_event_field(&triggers, "github.event.ref"));
+    }
+
+    #[test]
+    fn non_event_path_is_always_valid() {
+        let triggers = vec!["push".to_string()];
+        assert!(is_valid_event_field(&triggers, "github.sha"));
+    }
+}
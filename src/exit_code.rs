@@ -0,0 +1,93 @@
+//! Exit code policy: decides whether a set of findings should fail a run.
+//!
+//! Severity here is always the *effective* severity (after config
+//! overrides have been applied by the audit that produced the finding),
+//! so this module doesn't need to consult [`crate::config::Config`] itself.
+
+use crate::finding::{Finding, Severity};
+
+pub const SUCCESS: i32 = 0;
+pub const FINDINGS_AT_THRESHOLD: i32 = 1;
+/// `zizmor.yml` itself is invalid - a typo'd rule id, a malformed
+/// severity override, etc. Distinct from [`OPERATIONAL_ERROR`] so a CI
+/// script can tell "fix your config" apart from "the scan itself broke".
+pub const CONFIG_ERROR: i32 = 2;
+/// Something outside of findings/config went wrong - a workflow file
+/// that couldn't be read or parsed, a network call that failed, and so
+/// on.
+pub const OPERATIONAL_ERROR: i32 = 3;
+
+/// Returns [`FINDINGS_AT_THRESHOLD`] if any finding's effective severity
+/// meets or exceeds `threshold`, otherwise [`SUCCESS`].
+pub fn compute(findings: &[Finding], threshold: Severity) -> i32 {
+    if findings.iter().any(|f| f.severity >= threshold) {
+        FINDINGS_AT_THRESHOLD
+    } else {
+        SUCCESS
+    }
+}
+
+/// An error classified for exit-code purposes, so `main` can map it to
+/// [`CONFIG_ERROR`] instead of the generic [`OPERATIONAL_ERROR`] every
+/// other `anyhow::Error` gets.
+#[derive(Debug)]
+pub struct ConfigError(pub String);
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// The exit code `main` should use for an error returned from its
+/// fallible body: [`CONFIG_ERROR`] if it's (or wraps) a [`ConfigError`],
+/// [`OPERATIONAL_ERROR`] otherwise.
+pub fn for_error(err: &anyhow::Error) -> i32 {
+    if err.downcast_ref::<ConfigError>().is_some() {
+        CONFIG_ERROR
+    } else {
+        OPERATIONAL_ERROR
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::finding::Confidence;
+
+    #[test]
+    fn threshold_honors_overridden_severity() {
+        let findings = vec![Finding::new("missing-timeout", "no timeout set")
+            .with_severity(Severity::Informational)
+            .with_confidence(Confidence::Low)];
+
+        assert_eq!(compute(&findings, Severity::Medium), SUCCESS);
+        assert_eq!(compute(&findings, Severity::Informational), FINDINGS_AT_THRESHOLD);
+    }
+
+    #[test]
+    fn for_error_maps_config_error_to_config_error_code() {
+        let err: anyhow::Error = ConfigError("1 error(s) in zizmor.yml".into()).into();
+        assert_eq!(for_error(&err), CONFIG_ERROR);
+    }
+
+    #[test]
+    fn for_error_maps_a_config_load_failure_wrapped_as_config_error() {
+        // `Config::load_with_extends` failures (malformed YAML, an
+        // `extends` cycle) aren't `ConfigError` at their origin - `main`
+        // re-wraps them before propagating, same as the validate()
+        // diagnostics path above.
+        let parse_err: anyhow::Error = serde_yaml::from_str::<Config>("not: [valid").unwrap_err().into();
+        let wrapped: anyhow::Error = ConfigError(parse_err.to_string()).into();
+        assert_eq!(for_error(&wrapped), CONFIG_ERROR);
+    }
+
+    #[test]
+    fn for_error_defaults_to_operational_error() {
+        let err = anyhow::anyhow!("workflow file not found");
+        assert_eq!(for_error(&err), OPERATIONAL_ERROR);
+    }
+}
@@ -0,0 +1,158 @@
+//! Symbolic evaluation of a handful of pure built-in expression
+//! functions (`format`, `contains`, `startsWith`, `join`, `hashFiles`),
+//! so callers that need to know whether a `${{ ... }}` expression is a
+//! compile-time constant - or which context paths it reads - can see
+//! through a wrapper like `format('{0}', github.event.issue.title)`
+//! instead of only recognizing a bare context access.
+
+use super::parser::Expr;
+
+/// A fully-evaluated literal result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Str(String),
+    Num(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn render(&self) -> String {
+        match self {
+            Value::Str(s) => s.clone(),
+            Value::Num(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+/// Built-in functions that are pure: their result depends only on their
+/// own arguments (or, for `hashFiles`, on repository file contents),
+/// never on ambient workflow context beyond what's passed to them.
+const PURE_FUNCTIONS: &[&str] = &["format", "contains", "startsWith", "join", "hashFiles"];
+
+pub fn is_pure_function(name: &str) -> bool {
+    PURE_FUNCTIONS.contains(&name)
+}
+
+/// Evaluates `expr` to a literal [`Value`] if it's made up entirely of
+/// literals and calls to the pure functions above - `hashFiles` excepted,
+/// since its result depends on file contents this crate has no access
+/// to and so is never foldable to a literal here.
+pub fn eval(expr: &Expr) -> Option<Value> {
+    match expr {
+        Expr::Number(n) => Some(Value::Num(*n)),
+        Expr::Str(s) => Some(Value::Str(s.clone())),
+        Expr::Call(name, args) => eval_call(name, args),
+        _ => None,
+    }
+}
+
+fn eval_call(name: &str, args: &[Expr]) -> Option<Value> {
+    match name {
+        "format" => {
+            let (fmt, rest) = args.split_first()?;
+            let Value::Str(fmt) = eval(fmt)? else { return None };
+            let mut out = fmt;
+            for (i, arg) in rest.iter().enumerate() {
+                out = out.replace(&format!("{{{i}}}"), &eval(arg)?.render());
+            }
+            Some(Value::Str(out))
+        }
+        "contains" => {
+            let [haystack, needle] = args else { return None };
+            Some(Value::Bool(eval(haystack)?.render().contains(&eval(needle)?.render())))
+        }
+        "startsWith" => {
+            let [s, prefix] = args else { return None };
+            Some(Value::Bool(eval(s)?.render().starts_with(&eval(prefix)?.render())))
+        }
+        "join" => {
+            // `join` treats a non-array argument as a single-element
+            // array (per GitHub's own docs), so a literal string or
+            // number joins to itself regardless of the separator - there's
+            // nothing else to join with. Real arrays aren't modeled in
+            // this AST, so that's the only shape foldable here.
+            eval(args.first()?)
+        }
+        // `hashFiles` depends on repository file contents at runtime,
+        // which this crate can't see, so it's left unevaluated even
+        // though it's pure (see `is_pure_function`).
+        _ => None,
+    }
+}
+
+/// Collects every context path (`github.event.issue.title`, `env.FOO`,
+/// ...) that `expr` reads, looking through function calls and operators
+/// so a taint check on the result covers
+/// `format('{0}', github.event.issue.title)` the same way it covers a
+/// bare `github.event.issue.title`.
+pub fn context_paths(expr: &Expr) -> Vec<String> {
+    let mut paths = vec![];
+    collect_context_paths(expr, &mut paths);
+    paths
+}
+
+fn collect_context_paths(expr: &Expr, out: &mut Vec<String>) {
+    match expr {
+        Expr::Member(..) => {
+            if let Some(path) = member_path(expr) {
+                out.push(path);
+            }
+        }
+        Expr::Index(base, index) => {
+            collect_context_paths(base, out);
+            collect_context_paths(index, out);
+        }
+        Expr::Call(_, args) => {
+            for arg in args {
+                collect_context_paths(arg, out);
+            }
+        }
+        Expr::BinOp(_, lhs, rhs) => {
+            collect_context_paths(lhs, out);
+            collect_context_paths(rhs, out);
+        }
+        Expr::Ident(_) | Expr::Number(_) | Expr::Str(_) => {}
+    }
+}
+
+/// Reconstructs the dotted path of a `Member` chain, e.g.
+/// `github.event.issue.title`, so it can be looked up in
+/// [`crate::taint`]'s knowledge base.
+fn member_path(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Ident(name) => Some(name.clone()),
+        Expr::Member(base, field) => Some(format!("{}.{field}", member_path(base)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::parse;
+
+    #[test]
+    fn folds_format_with_literal_args() {
+        let expr = parse("format('{0}-{1}', 'a', 'b')").unwrap();
+        assert_eq!(eval(&expr), Some(Value::Str("a-b".into())));
+    }
+
+    #[test]
+    fn join_of_single_string_is_identity() {
+        let expr = parse("join('a', ',')").unwrap();
+        assert_eq!(eval(&expr), Some(Value::Str("a".into())));
+    }
+
+    #[test]
+    fn hash_files_is_never_constant() {
+        let expr = parse("hashFiles('**/*.lock')").unwrap();
+        assert_eq!(eval(&expr), None);
+    }
+
+    #[test]
+    fn context_paths_sees_through_format_wrapper() {
+        let expr = parse("format('issue: {0}', github.event.issue.title)").unwrap();
+        assert_eq!(context_paths(&expr), vec!["github.event.issue.title".to_string()]);
+    }
+}
@@ -0,0 +1,130 @@
+//! Tokenizer for the inside of a `${{ ... }}` expression.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    Ident(String),
+    Number(String),
+    String(String),
+    Dot,
+    Comma,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Op(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: std::ops::Range<usize>,
+}
+
+pub fn lex(input: &str) -> anyhow::Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        match c {
+            c if c.is_whitespace() => {
+                i += 1;
+            }
+            '.' => {
+                tokens.push(Token {
+                    kind: TokenKind::Dot,
+                    span: start..start + 1,
+                });
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token {
+                    kind: TokenKind::Comma,
+                    span: start..start + 1,
+                });
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token {
+                    kind: TokenKind::LParen,
+                    span: start..start + 1,
+                });
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token {
+                    kind: TokenKind::RParen,
+                    span: start..start + 1,
+                });
+                i += 1;
+            }
+            '[' => {
+                tokens.push(Token {
+                    kind: TokenKind::LBracket,
+                    span: start..start + 1,
+                });
+                i += 1;
+            }
+            ']' => {
+                tokens.push(Token {
+                    kind: TokenKind::RBracket,
+                    span: start..start + 1,
+                });
+                i += 1;
+            }
+            '\'' => {
+                i += 1;
+                let mut s = String::new();
+                while i < chars.len() && chars[i] != '\'' {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                anyhow::ensure!(i < chars.len(), "unterminated string literal at byte {start}");
+                i += 1;
+                tokens.push(Token {
+                    kind: TokenKind::String(s),
+                    span: start..i,
+                });
+            }
+            '=' | '!' | '<' | '>' | '&' | '|' => {
+                let mut op = String::from(c);
+                i += 1;
+                if i < chars.len() && (chars[i] == '=' || (op == "&" && chars[i] == '&') || (op == "|" && chars[i] == '|')) {
+                    op.push(chars[i]);
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Op(op),
+                    span: start..i,
+                });
+            }
+            c if c.is_ascii_digit() => {
+                let mut s = String::new();
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Number(s),
+                    span: start..i,
+                });
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut s = String::new();
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '-') {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                tokens.push(Token {
+                    kind: TokenKind::Ident(s),
+                    span: start..i,
+                });
+            }
+            other => anyhow::bail!("unexpected character `{other}` at byte {start}"),
+        }
+    }
+
+    Ok(tokens)
+}
@@ -0,0 +1,41 @@
+//! A real parser for GitHub Actions `${{ ... }}` expressions, replacing
+//! regex-based matching with an AST that downstream audits can reason
+//! about structurally (indexing, function calls, operators) instead of
+//! pattern-matching on strings.
+
+mod eval;
+mod lexer;
+mod parser;
+
+pub use eval::{context_paths, eval, is_pure_function, Value};
+pub use lexer::{Token, TokenKind};
+pub use parser::{parse, Expr, Span};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_context_access() {
+        let expr = parse("github.event.pull_request.title").unwrap();
+        assert_eq!(
+            expr,
+            Expr::Member(
+                Box::new(Expr::Member(
+                    Box::new(Expr::Member(
+                        Box::new(Expr::Ident("github".into())),
+                        "event".into()
+                    )),
+                    "pull_request".into()
+                )),
+                "title".into()
+            )
+        );
+    }
+
+    #[test]
+    fn parses_function_call() {
+        let expr = parse("contains(github.event.head_commit.message, 'skip-ci')").unwrap();
+        assert!(matches!(expr, Expr::Call(name, args) if name == "contains" && args.len() == 2));
+    }
+}
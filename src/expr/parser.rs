@@ -0,0 +1,147 @@
+//! Recursive-descent parser producing an [`Expr`] AST from [`Token`]s.
+
+use super::lexer::{lex, Token, TokenKind};
+
+pub type Span = std::ops::Range<usize>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Ident(String),
+    Number(f64),
+    Str(String),
+    Member(Box<Expr>, String),
+    Index(Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+    BinOp(String, Box<Expr>, Box<Expr>),
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.pos).map(|t| &t.kind)
+    }
+
+    fn bump(&mut self) -> Option<TokenKind> {
+        let tok = self.tokens.get(self.pos).map(|t| t.kind.clone());
+        self.pos += 1;
+        tok
+    }
+
+    fn expect(&mut self, kind: &TokenKind) -> anyhow::Result<()> {
+        match self.bump() {
+            Some(ref got) if got == kind => Ok(()),
+            got => anyhow::bail!("expected {kind:?}, found {got:?}"),
+        }
+    }
+
+    /// Parses a full expression, honoring the usual precedence - `||`
+    /// loosest, then `&&`, then the comparison operators - so `a == b
+    /// && c` groups as `(a == b) && c` rather than `a == (b && c)`.
+    fn parse_expr(&mut self) -> anyhow::Result<Expr> {
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> anyhow::Result<Expr> {
+        let mut lhs = self.parse_and()?;
+        while let Some(TokenKind::Op(op)) = self.peek().cloned() {
+            if op != "||" {
+                break;
+            }
+            self.bump();
+            let rhs = self.parse_and()?;
+            lhs = Expr::BinOp(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> anyhow::Result<Expr> {
+        let mut lhs = self.parse_comparison()?;
+        while let Some(TokenKind::Op(op)) = self.peek().cloned() {
+            if op != "&&" {
+                break;
+            }
+            self.bump();
+            let rhs = self.parse_comparison()?;
+            lhs = Expr::BinOp(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_comparison(&mut self) -> anyhow::Result<Expr> {
+        let lhs = self.parse_postfix()?;
+        if let Some(TokenKind::Op(op)) = self.peek().cloned() {
+            if op != "&&" && op != "||" {
+                self.bump();
+                let rhs = self.parse_postfix()?;
+                return Ok(Expr::BinOp(op, Box::new(lhs), Box::new(rhs)));
+            }
+        }
+        Ok(lhs)
+    }
+
+    fn parse_postfix(&mut self) -> anyhow::Result<Expr> {
+        let mut expr = self.parse_atom()?;
+        loop {
+            match self.peek() {
+                Some(TokenKind::Dot) => {
+                    self.bump();
+                    match self.bump() {
+                        Some(TokenKind::Ident(name)) => expr = Expr::Member(Box::new(expr), name),
+                        other => anyhow::bail!("expected field name after `.`, found {other:?}"),
+                    }
+                }
+                Some(TokenKind::LBracket) => {
+                    self.bump();
+                    let index = self.parse_expr()?;
+                    self.expect(&TokenKind::RBracket)?;
+                    expr = Expr::Index(Box::new(expr), Box::new(index));
+                }
+                _ => break,
+            }
+        }
+        Ok(expr)
+    }
+
+    fn parse_atom(&mut self) -> anyhow::Result<Expr> {
+        match self.bump() {
+            Some(TokenKind::Ident(name)) => {
+                if self.peek() == Some(&TokenKind::LParen) {
+                    self.bump();
+                    let mut args = vec![];
+                    if self.peek() != Some(&TokenKind::RParen) {
+                        args.push(self.parse_expr()?);
+                        while self.peek() == Some(&TokenKind::Comma) {
+                            self.bump();
+                            args.push(self.parse_expr()?);
+                        }
+                    }
+                    self.expect(&TokenKind::RParen)?;
+                    Ok(Expr::Call(name, args))
+                } else {
+                    Ok(Expr::Ident(name))
+                }
+            }
+            Some(TokenKind::Number(n)) => Ok(Expr::Number(n.parse()?)),
+            Some(TokenKind::String(s)) => Ok(Expr::Str(s)),
+            Some(TokenKind::LParen) => {
+                let inner = self.parse_expr()?;
+                self.expect(&TokenKind::RParen)?;
+                Ok(inner)
+            }
+            other => anyhow::bail!("unexpected token {other:?}"),
+        }
+    }
+}
+
+/// Parses a single expression (the contents of one `${{ ... }}`).
+pub fn parse(input: &str) -> anyhow::Result<Expr> {
+    let tokens = lex(input)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_expr()?;
+    anyhow::ensure!(parser.pos == parser.tokens.len(), "trailing input after expression");
+    Ok(expr)
+}
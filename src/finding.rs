@@ -0,0 +1,244 @@
+//! Findings produced by audits, along with their severity/confidence and
+//! the locations in source they point back to.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use camino::Utf8PathBuf;
+use serde::{Deserialize, Serialize};
+
+/// How bad a finding is, independent of how sure we are about it.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    Unknown,
+    Informational,
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Severity::Unknown => "unknown",
+            Severity::Informational => "informational",
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+        };
+        f.write_str(s)
+    }
+}
+
+/// How sure an audit is about a finding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+/// A single key/index step within a workflow document, used to build up
+/// a [`Route`] to the YAML node a finding is about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RouteComponent {
+    Key(String),
+    Index(usize),
+}
+
+/// A path of [`RouteComponent`]s from the document root to a specific node,
+/// e.g. `jobs.build.steps[2].with.ref`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Route(pub Vec<RouteComponent>);
+
+impl Route {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.0.push(RouteComponent::Key(key.into()));
+        self
+    }
+
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.0.push(RouteComponent::Index(index));
+        self
+    }
+
+    /// `on.<trigger>` - the root of one of a workflow's trigger
+    /// declarations.
+    pub fn on_trigger(trigger: impl Into<String>) -> Self {
+        Self::new().with_key("on").with_key(trigger)
+    }
+
+    /// `jobs.<job_id>` - the root of a job's declaration.
+    pub fn job(job_id: impl Into<String>) -> Self {
+        Self::new().with_key("jobs").with_key(job_id)
+    }
+
+    /// `jobs.<job_id>.steps[<index>]` - the root of one of its steps.
+    pub fn step(job_id: impl Into<String>, index: usize) -> Self {
+        Self::job(job_id).with_key("steps").with_index(index)
+    }
+
+    /// `jobs.<job_id>.strategy.matrix.include[<index>]` - one entry of
+    /// an explicit matrix `include:` list.
+    pub fn matrix_include(job_id: impl Into<String>, index: usize) -> Self {
+        Self::job(job_id).with_key("strategy").with_key("matrix").with_key("include").with_index(index)
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, component) in self.0.iter().enumerate() {
+            match component {
+                RouteComponent::Key(key) => {
+                    if i > 0 {
+                        write!(f, ".")?;
+                    }
+                    write!(f, "{key}")?;
+                }
+                RouteComponent::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A location within a workflow or action file that a finding refers to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolicLocation {
+    pub path: Utf8PathBuf,
+    pub route: Route,
+    /// Human-facing annotation shown alongside this location.
+    pub annotation: String,
+    /// Byte range into the file's raw source, when an audit was able to
+    /// resolve one - either the whole node the `route` points at, or (via
+    /// [`crate::span::resolve_scalar_span`]) a sub-range within a YAML
+    /// scalar, such as the exact `${{ ... }}` expression inside a `run:`
+    /// block rather than the whole script. `None` when only the
+    /// symbolic route is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<std::ops::Range<usize>>,
+}
+
+/// Leaks a deserialized rule id to `&'static str`, the same way
+/// [`crate::audit::custom_rule::CustomRuleAudit`] does for user-defined
+/// rule ids - `Finding::ident` is `&'static str` for every audit, so a
+/// `Finding` read back from a cache needs one too.
+fn leak_ident(id: String) -> &'static str {
+    Box::leak(id.into_boxed_str())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding {
+    /// The audit identifier, e.g. `unpinned-uses`.
+    pub ident: &'static str,
+    pub desc: String,
+    pub severity: Severity,
+    pub confidence: Confidence,
+    pub locations: Vec<SymbolicLocation>,
+}
+
+// `ident` is `&'static str`, which would force serde-derive's generated
+// `Deserialize` impl to require the deserializer's `'de` to outlive
+// `'static` (the field type leaks into the impl's bounds even though
+// `leak_ident` never actually borrows from the input). Deserializing by
+// hand through an owned shadow struct sidesteps that entirely.
+impl<'de> Deserialize<'de> for Finding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            ident: String,
+            desc: String,
+            severity: Severity,
+            confidence: Confidence,
+            locations: Vec<SymbolicLocation>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(Finding {
+            ident: leak_ident(raw.ident),
+            desc: raw.desc,
+            severity: raw.severity,
+            confidence: raw.confidence,
+            locations: raw.locations,
+        })
+    }
+}
+
+impl Finding {
+    pub fn new(ident: &'static str, desc: impl Into<String>) -> Self {
+        Self {
+            ident,
+            desc: desc.into(),
+            severity: Severity::Unknown,
+            confidence: Confidence::Low,
+            locations: vec![],
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    pub fn with_location(mut self, location: SymbolicLocation) -> Self {
+        self.locations.push(location);
+        self
+    }
+}
+
+impl PartialEq for Finding {
+    fn eq(&self, other: &Self) -> bool {
+        self.ident == other.ident && self.severity == other.severity
+    }
+}
+impl Eq for Finding {}
+
+impl PartialOrd for Finding {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Finding {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Highest severity first.
+        other.severity.cmp(&self.severity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_nested_route_as_dotted_path() {
+        let route = Route::new()
+            .with_key("on")
+            .with_key("pull_request")
+            .with_key("paths")
+            .with_index(2);
+        assert_eq!(route.to_string(), "on.pull_request.paths[2]");
+    }
+
+    #[test]
+    fn step_helper_matches_manual_route() {
+        let manual = Route::new().with_key("jobs").with_key("build").with_key("steps").with_index(1);
+        assert_eq!(Route::step("build", 1), manual);
+    }
+}
@@ -0,0 +1,93 @@
+//! `--fix`: format-preserving autofixes.
+//!
+//! A [`Fix`] is a byte-range replacement against the *original* source
+//! text. Applying a batch of fixes never touches anything outside the
+//! given ranges, so comments, quoting style, and formatting everywhere
+//! else in the document are left exactly as the user wrote them.
+
+/// A single text edit: replace `span` (byte offsets into the original
+/// source) with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub span: std::ops::Range<usize>,
+    pub replacement: String,
+    /// Short human-facing description, e.g. "pin to commit SHA".
+    pub description: String,
+}
+
+/// Renders the effect of `fixes` on `source` as a unified diff, for
+/// `--fix --dry-run` (or `--format patch`) review without touching the
+/// file on disk.
+pub fn as_patch(path: &str, source: &str, fixes: &[Fix]) -> anyhow::Result<String> {
+    let fixed = apply(source, fixes)?;
+    let diff = similar::TextDiff::from_lines(source, &fixed);
+    Ok(diff
+        .unified_diff()
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string())
+}
+
+/// Applies non-overlapping `fixes` to `source`, returning the edited
+/// text. Fixes are applied right-to-left by span start so earlier spans
+/// remain valid as later edits shift the string.
+pub fn apply(source: &str, fixes: &[Fix]) -> anyhow::Result<String> {
+    let mut fixes = fixes.to_vec();
+    fixes.sort_by_key(|f| std::cmp::Reverse(f.span.start));
+
+    for window in fixes.windows(2) {
+        let [a, b] = window else { unreachable!() };
+        anyhow::ensure!(
+            a.span.start >= b.span.end,
+            "overlapping fixes: {:?} and {:?}",
+            a.span,
+            b.span
+        );
+    }
+
+    let mut out = source.to_string();
+    for fix in &fixes {
+        anyhow::ensure!(
+            fix.span.end <= out.len(),
+            "fix span {:?} is out of bounds for a {}-byte document",
+            fix.span,
+            out.len()
+        );
+        out.replace_range(fix.span.clone(), &fix.replacement);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_fixes_without_disturbing_untouched_text() {
+        let source = "uses: actions/checkout@v4 # pinned by hand\n";
+        let fixes = vec![Fix {
+            span: 6..25,
+            replacement: "actions/checkout@deadbeef".into(),
+            description: "pin to commit SHA".into(),
+        }];
+        let fixed = apply(source, &fixes).unwrap();
+        assert_eq!(fixed, "uses: actions/checkout@deadbeef # pinned by hand\n");
+    }
+
+    #[test]
+    fn rejects_overlapping_fixes() {
+        let source = "abcdef";
+        let fixes = vec![
+            Fix {
+                span: 0..3,
+                replacement: "X".into(),
+                description: "a".into(),
+            },
+            Fix {
+                span: 2..4,
+                replacement: "Y".into(),
+                description: "b".into(),
+            },
+        ];
+        assert!(apply(source, &fixes).is_err());
+    }
+}
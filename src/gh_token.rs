@@ -0,0 +1,105 @@
+//! Resolution of the GitHub token used by online audits, in the same
+//! order the `gh` CLI itself uses so behavior is predictable.
+
+use std::fmt;
+
+/// Where a resolved token came from, surfaced to the user so they know
+/// why online audits were (or weren't) enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource {
+    Flag,
+    GhToken,
+    GithubToken,
+    GhCli,
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TokenSource::Flag => "--gh-token",
+            TokenSource::GhToken => "GH_TOKEN",
+            TokenSource::GithubToken => "GITHUB_TOKEN",
+            TokenSource::GhCli => "gh auth token",
+        };
+        f.write_str(s)
+    }
+}
+
+pub struct ResolvedToken {
+    pub token: String,
+    pub source: TokenSource,
+}
+
+/// Resolves a GitHub token in priority order: `--gh-token` flag, then
+/// `GH_TOKEN`, then `GITHUB_TOKEN`, then shelling out to `gh auth token`.
+/// Returns `None` (not an error) if no source yields a token, since
+/// running fully offline is a supported mode.
+pub fn resolve(flag: Option<&str>) -> Option<ResolvedToken> {
+    if let Some(token) = flag {
+        return Some(ResolvedToken {
+            token: token.to_string(),
+            source: TokenSource::Flag,
+        });
+    }
+    if let Ok(token) = std::env::var("GH_TOKEN") {
+        if !token.is_empty() {
+            return Some(ResolvedToken {
+                token,
+                source: TokenSource::GhToken,
+            });
+        }
+    }
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        if !token.is_empty() {
+            return Some(ResolvedToken {
+                token,
+                source: TokenSource::GithubToken,
+            });
+        }
+    }
+    // `std::process::Command` has no `wasm32-unknown-unknown` target
+    // support, so this fallback - the least important of the four, and
+    // irrelevant in a browser anyway - is the one dropped there rather
+    // than the whole module.
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Ok(output) = std::process::Command::new("gh").args(["auth", "token"]).output() {
+        if output.status.success() {
+            let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            if !token.is_empty() {
+                return Some(ResolvedToken {
+                    token,
+                    source: TokenSource::GhCli,
+                });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn flag_takes_priority_over_env() {
+        std::env::set_var("GH_TOKEN", "env-token");
+        let resolved = resolve(Some("flag-token")).unwrap();
+        assert_eq!(resolved.token, "flag-token");
+        assert_eq!(resolved.source, TokenSource::Flag);
+        std::env::remove_var("GH_TOKEN");
+    }
+
+    #[test]
+    #[serial]
+    fn gh_token_takes_priority_over_github_token() {
+        std::env::set_var("GH_TOKEN", "gh-token-value");
+        std::env::set_var("GITHUB_TOKEN", "github-token-value");
+        let resolved = resolve(None).unwrap();
+        assert_eq!(resolved.token, "gh-token-value");
+        assert_eq!(resolved.source, TokenSource::GhToken);
+        std::env::remove_var("GH_TOKEN");
+        std::env::remove_var("GITHUB_TOKEN");
+    }
+}
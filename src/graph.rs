@@ -0,0 +1,154 @@
+//! The `needs:` dependency graph between a workflow's jobs, as
+//! infrastructure for both a correctness audit (missing references,
+//! cycles) and cross-job dataflow analyses that need to walk the graph.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::Workflow;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobGraph {
+    edges: HashMap<String, Vec<String>>,
+}
+
+impl JobGraph {
+    pub fn job_ids(&self) -> impl Iterator<Item = &str> {
+        self.edges.keys().map(String::as_str)
+    }
+
+    /// `(job_id, missing_need)` pairs for `needs:` entries that don't
+    /// name a job defined in the workflow.
+    pub fn missing_dependencies(&self) -> Vec<(String, String)> {
+        let known: HashSet<&str> = self.edges.keys().map(String::as_str).collect();
+        let mut missing = vec![];
+        for (job_id, needs) in &self.edges {
+            for need in needs {
+                if !known.contains(need.as_str()) {
+                    missing.push((job_id.clone(), need.clone()));
+                }
+            }
+        }
+        missing.sort();
+        missing
+    }
+
+    /// Returns one cycle per strongly-connected loop found, as the
+    /// sequence of job ids that form it. Jobs with a missing dependency
+    /// are skipped here since [`Self::missing_dependencies`] already
+    /// covers that case.
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles = vec![];
+        let mut visited: HashSet<&str> = HashSet::new();
+
+        for start in self.edges.keys() {
+            if visited.contains(start.as_str()) {
+                continue;
+            }
+            let mut stack = vec![];
+            if let Some(cycle) = self.dfs(start, &mut stack, &mut visited) {
+                cycles.push(cycle);
+            }
+        }
+
+        cycles
+    }
+
+    fn dfs<'a>(&'a self, job_id: &'a str, stack: &mut Vec<&'a str>, visited: &mut HashSet<&'a str>) -> Option<Vec<String>> {
+        if let Some(pos) = stack.iter().position(|j| *j == job_id) {
+            return Some(stack[pos..].iter().map(|s| s.to_string()).collect());
+        }
+        if visited.contains(job_id) {
+            return None;
+        }
+
+        stack.push(job_id);
+        let mut result = None;
+        if let Some(needs) = self.edges.get(job_id) {
+            for need in needs {
+                if !self.edges.contains_key(need.as_str()) {
+                    continue;
+                }
+                if let Some(cycle) = self.dfs(need.as_str(), stack, visited) {
+                    result = Some(cycle);
+                    break;
+                }
+            }
+        }
+        stack.pop();
+        visited.insert(job_id);
+        result
+    }
+
+    /// A topological order of job ids, or `None` if the graph has a
+    /// cycle or a dependency on an undefined job.
+    pub fn topo_order(&self) -> Option<Vec<String>> {
+        if !self.missing_dependencies().is_empty() || !self.cycles().is_empty() {
+            return None;
+        }
+
+        let mut order = vec![];
+        let mut done: HashSet<&str> = HashSet::new();
+
+        while done.len() < self.edges.len() {
+            let ready: Vec<&str> = self
+                .edges
+                .iter()
+                .filter(|(id, _)| !done.contains(id.as_str()))
+                .filter(|(_, needs)| needs.iter().all(|n| done.contains(n.as_str())))
+                .map(|(id, _)| id.as_str())
+                .collect();
+            if ready.is_empty() {
+                return None;
+            }
+            let mut ready = ready;
+            ready.sort();
+            for id in ready {
+                done.insert(id);
+                order.push(id.to_string());
+            }
+        }
+
+        Some(order)
+    }
+}
+
+impl Workflow {
+    pub fn job_graph(&self) -> JobGraph {
+        let edges = self.jobs.iter().map(|(id, job)| (id.clone(), job.needs.clone())).collect();
+        JobGraph { edges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workflow(yaml: &str) -> Workflow {
+        Workflow::from_string("w.yml", yaml).unwrap()
+    }
+
+    #[test]
+    fn detects_missing_dependency() {
+        let workflow = workflow("on: push\njobs:\n  b:\n    needs: [a]\n    runs-on: ubuntu-latest\n    steps: []\n");
+        let graph = workflow.job_graph();
+        assert_eq!(graph.missing_dependencies(), vec![("b".to_string(), "a".to_string())]);
+    }
+
+    #[test]
+    fn detects_cycle() {
+        let workflow = workflow(
+            "on: push\njobs:\n  a:\n    needs: [b]\n    runs-on: ubuntu-latest\n    steps: []\n  b:\n    needs: [a]\n    runs-on: ubuntu-latest\n    steps: []\n",
+        );
+        let graph = workflow.job_graph();
+        assert_eq!(graph.cycles().len(), 1);
+    }
+
+    #[test]
+    fn topo_order_respects_needs() {
+        let workflow = workflow(
+            "on: push\njobs:\n  b:\n    needs: [a]\n    runs-on: ubuntu-latest\n    steps: []\n  a:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        );
+        let graph = workflow.job_graph();
+        assert_eq!(graph.topo_order(), Some(vec!["a".to_string(), "b".to_string()]));
+    }
+}
@@ -0,0 +1,105 @@
+//! Serializable intermediate representation of a workflow's resolved
+//! analysis state - normalized triggers, per-job effective permissions
+//! and env, the `uses:` references each job pulls in, and the taint
+//! facts that apply to its triggers - so `zizmor ir` and out-of-tree
+//! plugin authors can build on the same analysis zizmor's own audits
+//! use instead of re-deriving it from raw YAML.
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::models::Workflow;
+use crate::permissions::{self, DefaultPermissions, EffectivePermissions};
+use crate::taint::{known_paths, taint_of, Taint};
+use crate::triggers::Triggers;
+
+#[derive(Debug, Serialize)]
+pub struct WorkflowIr {
+    pub path: String,
+    pub triggers: Vec<String>,
+    pub jobs: IndexMap<String, JobIr>,
+    pub taint_facts: Vec<TaintFact>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JobIr {
+    pub effective_permissions: EffectivePermissions,
+    pub effective_env: IndexMap<String, String>,
+    pub uses: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaintFact {
+    pub trigger: String,
+    pub context_path: String,
+    pub taint: Taint,
+}
+
+/// Builds the IR for `workflow`. GitHub's own token-permission default
+/// depends on an org/repo setting this crate can't see, so callers that
+/// need the other branch should call [`permissions::resolve`] directly;
+/// `zizmor ir` assumes the modern restricted default, matching
+/// [`crate::audit::excessive_permissions`].
+pub fn build(workflow: &Workflow) -> WorkflowIr {
+    let triggers = Triggers::new(&workflow.on).events();
+
+    let jobs = workflow
+        .jobs
+        .iter()
+        .map(|(job_id, job)| {
+            let mut effective_env = workflow.env.clone();
+            effective_env.extend(job.env.iter().map(|(k, v)| (k.clone(), v.clone())));
+            let uses = job.steps.iter().filter_map(|step| step.uses.clone()).collect();
+
+            (
+                job_id.clone(),
+                JobIr {
+                    effective_permissions: permissions::resolve(workflow, job_id, DefaultPermissions::Restricted),
+                    effective_env,
+                    uses,
+                },
+            )
+        })
+        .collect();
+
+    let taint_facts = triggers
+        .iter()
+        .flat_map(|trigger| {
+            known_paths().into_iter().map(move |path| TaintFact {
+                trigger: trigger.clone(),
+                context_path: path.to_string(),
+                taint: taint_of(trigger, path),
+            })
+        })
+        .collect();
+
+    WorkflowIr {
+        path: workflow.path.to_string(),
+        triggers,
+        jobs,
+        taint_facts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_ir_with_triggers_and_job_env() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: pull_request_target\nenv:\n  FOO: bar\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n",
+        )
+        .unwrap();
+
+        let ir = build(&workflow);
+        assert_eq!(ir.triggers, vec!["pull_request_target".to_string()]);
+        assert_eq!(ir.jobs["j"].effective_env.get("FOO"), Some(&"bar".to_string()));
+        assert_eq!(ir.jobs["j"].uses, vec!["actions/checkout@v4".to_string()]);
+        assert!(ir
+            .taint_facts
+            .iter()
+            .any(|f| f.context_path == "github.event.pull_request.title" && f.taint == Taint::AttackerControlled));
+    }
+}
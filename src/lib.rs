@@ -0,0 +1,257 @@
+//! Core analysis library for zizmor, a static analysis tool for GitHub
+//! Actions workflows and actions.
+//!
+//! Embedders that want to run zizmor's analysis in-process rather than
+//! shelling out to the CLI and parsing its text output need three
+//! things: a way to load a [`Workflow`]/[`Action`], a way to pick which
+//! [`Audit`]s to run, and a way to run them and get back typed
+//! [`Finding`]s. All three are public API:
+//!
+//! ```
+//! use zizmor::config::Config;
+//! use zizmor::models::Workflow;
+//! use zizmor::registry::default_audits;
+//!
+//! let workflow = Workflow::from_string(
+//!     "ci.yml",
+//!     "on: push\njobs:\n  build:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n",
+//! )?;
+//! let findings = zizmor::run_audits(&[workflow], &[], &default_audits(), &Config::default())?;
+//! assert!(findings.iter().any(|f| f.ident == "unpinned-uses"));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+pub mod audit;
+pub mod cache;
+pub mod clock;
+pub mod codeowners;
+pub mod comments;
+pub mod config;
+pub mod dataflow;
+pub mod depgraph;
+pub mod docs;
+pub mod enforce;
+pub mod env_resolution;
+pub mod event_schema;
+pub mod exit_code;
+pub mod expr;
+pub mod finding;
+pub mod fix;
+pub mod gh_token;
+pub mod graph;
+pub mod ir;
+pub mod matrix;
+pub mod models;
+pub mod net_pool;
+pub mod notify;
+pub mod org_scan;
+pub mod osv;
+pub mod output;
+pub mod permissions;
+pub mod persona;
+pub mod plugin;
+pub mod pre_commit;
+pub mod reachability;
+pub mod registry;
+pub mod reusable;
+pub mod review;
+pub mod rules;
+pub mod runner_labels;
+pub mod sarif_upload;
+pub mod sbom;
+pub mod scorecard;
+pub mod secrets;
+pub mod serve;
+pub mod shell;
+pub mod span;
+pub mod state;
+pub mod taint;
+pub mod timings;
+pub mod triggers;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+pub mod webhook;
+
+use audit::Audit;
+use finding::Finding;
+use models::{Action, Workflow};
+
+/// Runs `audits` over `workflows` and `actions` - per-workflow, then
+/// per-action, then the cross-workflow passes like reusable-workflow
+/// call binding that need the whole set at once - and returns every
+/// finding, sorted. This mirrors the sequencing the CLI itself uses in
+/// `main.rs`, as the single entry point embedders can call instead of
+/// re-deriving it.
+pub fn run_audits(workflows: &[Workflow], actions: &[Action], audits: &[Box<dyn Audit>], config: &config::Config) -> anyhow::Result<Vec<Finding>> {
+    let mut findings = vec![];
+
+    for workflow in workflows {
+        for audit in audits {
+            findings.extend(audit.audit_workflow(workflow, config)?);
+        }
+    }
+    for action in actions {
+        for audit in audits {
+            findings.extend(audit.audit_action(action, config)?);
+        }
+    }
+    for audit in audits {
+        findings.extend(audit.audit_workflow_set(workflows, config)?);
+    }
+
+    findings.sort();
+    Ok(findings)
+}
+
+/// Same contract as [`run_audits`], but skips re-auditing a workflow or
+/// action whose [`cache::key`] (content, `config_fingerprint`, and
+/// active audit set) already has a cached result under `cache_dir` from
+/// an earlier run, writing a fresh entry for anything it does audit.
+/// Cross-workflow audits always run, since their result depends on the
+/// whole scan set rather than one file.
+pub fn run_audits_cached(
+    workflows: &[Workflow],
+    actions: &[Action],
+    audits: &[Box<dyn Audit>],
+    config: &config::Config,
+    cache_dir: &camino::Utf8Path,
+    config_fingerprint: &str,
+) -> anyhow::Result<Vec<Finding>> {
+    let audit_ids: Vec<&str> = audits.iter().map(|audit| audit.ident()).collect();
+    let mut findings = vec![];
+
+    for workflow in workflows {
+        let cache_key = cache::key(workflow.path.as_str(), &workflow.raw, config_fingerprint, &audit_ids);
+        if let Some(cached) = cache::load(cache_dir, &cache_key) {
+            findings.extend(cached);
+            continue;
+        }
+        let mut per_file = vec![];
+        for audit in audits {
+            per_file.extend(audit.audit_workflow(workflow, config)?);
+        }
+        cache::store(cache_dir, &cache_key, &per_file);
+        findings.extend(per_file);
+    }
+    for action in actions {
+        let cache_key = cache::key(action.path.as_str(), &action.raw, config_fingerprint, &audit_ids);
+        if let Some(cached) = cache::load(cache_dir, &cache_key) {
+            findings.extend(cached);
+            continue;
+        }
+        let mut per_file = vec![];
+        for audit in audits {
+            per_file.extend(audit.audit_action(action, config)?);
+        }
+        cache::store(cache_dir, &cache_key, &per_file);
+        findings.extend(per_file);
+    }
+    for audit in audits {
+        findings.extend(audit.audit_workflow_set(workflows, config)?);
+    }
+
+    findings.sort();
+    Ok(findings)
+}
+
+/// Same contract as [`run_audits`], but also records how long each
+/// audit spent on each file into `report`, for `--timings`. Runs
+/// serially (like [`run_audits`], not [`run_audits_parallel`]) so the
+/// measured durations aren't skewed by contention between concurrent
+/// audits sharing CPU time.
+pub fn run_audits_timed(
+    workflows: &[Workflow],
+    actions: &[Action],
+    audits: &[Box<dyn Audit>],
+    config: &config::Config,
+    report: &mut timings::TimingReport,
+) -> anyhow::Result<Vec<Finding>> {
+    let mut findings = vec![];
+
+    for workflow in workflows {
+        for audit in audits {
+            let start = std::time::Instant::now();
+            let result = audit.audit_workflow(workflow, config)?;
+            report.record(audit.ident(), workflow.path.as_str(), start.elapsed());
+            findings.extend(result);
+        }
+    }
+    for action in actions {
+        for audit in audits {
+            let start = std::time::Instant::now();
+            let result = audit.audit_action(action, config)?;
+            report.record(audit.ident(), action.path.as_str(), start.elapsed());
+            findings.extend(result);
+        }
+    }
+    for audit in audits {
+        findings.extend(audit.audit_workflow_set(workflows, config)?);
+    }
+
+    findings.sort();
+    Ok(findings)
+}
+
+/// Same contract as [`run_audits`], but runs independent audits
+/// concurrently (via `rayon`) within each workflow/action and across
+/// workflows/actions at once, instead of one audit at a time. Audits
+/// that declare [`Audit::needs_exclusive_state`] still run serially,
+/// relative to the concurrent ones, for that workflow/action. Large
+/// scans over many workflows are the intended beneficiary; for a
+/// handful of files the overhead of spinning up rayon's thread pool can
+/// outweigh the gain, in which case [`run_audits`] is just as correct.
+pub fn run_audits_parallel(workflows: &[Workflow], actions: &[Action], audits: &[Box<dyn Audit>], config: &config::Config) -> anyhow::Result<Vec<Finding>> {
+    use rayon::prelude::*;
+
+    let (exclusive, concurrent): (Vec<_>, Vec<_>) = audits.iter().partition(|audit| audit.needs_exclusive_state());
+
+    let audit_workflow = |workflow: &Workflow| -> anyhow::Result<Vec<Finding>> {
+        let mut findings = concurrent
+            .par_iter()
+            .map(|audit| audit.audit_workflow(workflow, config))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        for audit in &exclusive {
+            findings.extend(audit.audit_workflow(workflow, config)?);
+        }
+        Ok(findings)
+    };
+    let audit_action = |action: &Action| -> anyhow::Result<Vec<Finding>> {
+        let mut findings = concurrent
+            .par_iter()
+            .map(|audit| audit.audit_action(action, config))
+            .collect::<anyhow::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        for audit in &exclusive {
+            findings.extend(audit.audit_action(action, config)?);
+        }
+        Ok(findings)
+    };
+
+    let mut findings: Vec<Finding> = workflows
+        .par_iter()
+        .map(audit_workflow)
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .chain(
+            actions
+                .par_iter()
+                .map(audit_action)
+                .collect::<anyhow::Result<Vec<_>>>()?
+                .into_iter()
+                .flatten(),
+        )
+        .collect();
+
+    for audit in audits {
+        findings.extend(audit.audit_workflow_set(workflows, config)?);
+    }
+
+    findings.sort();
+    Ok(findings)
+}
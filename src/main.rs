@@ -0,0 +1,375 @@
+mod bin_support;
+mod cli;
+mod env_overrides;
+
+use std::io::IsTerminal;
+
+use camino::Utf8Path;
+use clap::Parser;
+use zizmor::config::Config;
+use zizmor::exit_code;
+use zizmor::finding::Severity;
+use zizmor::models::{Action, Workflow};
+use zizmor::notify::NotificationSink;
+use zizmor::output::{render_plain, render_suppressions_appendix};
+use zizmor::registry::audits_for;
+use zizmor::review::ReviewPoster;
+use zizmor::sarif_upload::SarifUploader;
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("error: {err:#}");
+        std::process::exit(exit_code::for_error(&err));
+    }
+}
+
+fn run() -> anyhow::Result<()> {
+    let mut args = cli::Args::parse();
+    env_overrides::apply(&mut args);
+
+    match &args.command {
+        Some(cli::Command::Pin { path }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            return bin_support::pin::run(&path);
+        }
+        Some(cli::Command::Diff { a, b }) => {
+            return bin_support::diff::run(a, b);
+        }
+        Some(cli::Command::Init { path, output }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            return bin_support::init::run(&path, output);
+        }
+        Some(cli::Command::Ir { path }) => {
+            return bin_support::ir::run(path);
+        }
+        Some(cli::Command::SuggestUpdater { path, format }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            return bin_support::suggest_updater::run(&path, *format);
+        }
+        Some(cli::Command::Sbom { path, format }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            return bin_support::sbom::run(&path, *format);
+        }
+        Some(cli::Command::Graph { path, format }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            return bin_support::graph::run(&path, *format);
+        }
+        Some(cli::Command::Explain { rule_id }) => {
+            return bin_support::explain::run(rule_id);
+        }
+        Some(cli::Command::Rules { format }) => {
+            return bin_support::rules::run(*format);
+        }
+        Some(cli::Command::Serve { addr }) => {
+            return bin_support::serve::run(addr);
+        }
+        Some(cli::Command::Stats { path }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            return bin_support::stats::run(&path);
+        }
+        Some(cli::Command::Webhook { addr, secret }) => {
+            return bin_support::webhook_serve::run(addr, secret.as_deref());
+        }
+        Some(cli::Command::UpdatePins { path }) => {
+            let path = path.clone().unwrap_or_else(|| camino::Utf8PathBuf::from(".github"));
+            struct Unresolved;
+            impl bin_support::update_pins::LatestReleaseResolver for Unresolved {
+                fn latest(&self, _owner_repo: &str) -> anyhow::Result<Option<(String, String)>> {
+                    Ok(None)
+                }
+            }
+            return bin_support::update_pins::run(&path, &Unresolved);
+        }
+        None => {}
+    }
+
+    let mut config = match &args.config {
+        Some(path) => Config::load_with_extends(path).map_err(|e| zizmor::exit_code::ConfigError(e.to_string()))?,
+        None => {
+            let default_path = Utf8Path::new("zizmor.yml");
+            if default_path.exists() {
+                Config::load_with_extends(default_path).map_err(|e| zizmor::exit_code::ConfigError(e.to_string()))?
+            } else {
+                Config::default()
+            }
+        }
+    };
+
+    let diagnostics = zizmor::config::validate::validate(&config);
+    if !diagnostics.is_empty() {
+        for diagnostic in &diagnostics {
+            eprintln!("error: {diagnostic}");
+        }
+        return Err(zizmor::exit_code::ConfigError(format!("{} error(s) in zizmor.yml", diagnostics.len())).into());
+    }
+
+    let offline = zizmor::pre_commit::effective_offline(args.pre_commit, args.offline, args.gh_token.as_deref());
+    if !offline {
+        match zizmor::gh_token::resolve(args.gh_token.as_deref()) {
+            Some(resolved) => eprintln!("using GitHub token from {}; online audits enabled", resolved.source),
+            None => eprintln!("no GitHub token found; online audits disabled"),
+        }
+    }
+
+    // Tracked so --strict can fail a run that printed a warning instead
+    // of just letting it scroll by.
+    let mut saw_warning = false;
+
+    for expired in config.expired_ignores() {
+        eprintln!(
+            "warning: suppression for `{}` expired and no longer applies",
+            expired.rule
+        );
+        saw_warning = true;
+    }
+
+    let persona = if args.strict { zizmor::persona::Persona::Auditor } else { args.persona };
+    let audits: Vec<_> = audits_for(&config)
+        .into_iter()
+        .filter(|audit| persona.includes(audit.persona()))
+        .filter(|audit| args.only.is_empty() || args.only.iter().any(|id| id == audit.ident()))
+        .filter(|audit| !args.ignore.iter().any(|id| id == audit.ident()))
+        .collect();
+
+    // Parsing the OSV feed is only worth doing if `known-vulnerable-action`
+    // actually made it into the active audit set.
+    if audits.iter().any(|audit| audit.ident() == "known-vulnerable-action") {
+        config.ensure_osv_advisories_loaded()?;
+    }
+
+    let is_action_file = |input: &camino::Utf8PathBuf| matches!(input.file_stem(), Some("action"));
+    let (action_inputs, workflow_inputs): (Vec<_>, Vec<_>) = args.inputs.iter().cloned().partition(is_action_file);
+
+    let workflows: Vec<Workflow> = workflow_inputs
+        .iter()
+        .map(|input| Workflow::from_file(input))
+        .collect::<anyhow::Result<_>>()?;
+    let actions: Vec<Action> = action_inputs.iter().map(|input| Action::from_file(input)).collect::<anyhow::Result<_>>()?;
+
+    let findings = if args.pre_commit {
+        let budget = zizmor::pre_commit::Budget::new(std::time::Duration::from_secs(args.timeout_secs));
+
+        let mut findings = vec![];
+        'workflows: for workflow in &workflows {
+            for audit in &audits {
+                if budget.is_expired() {
+                    eprintln!("warning: --timeout-secs budget spent; some audits were skipped");
+                    saw_warning = true;
+                    break 'workflows;
+                }
+                findings.extend(audit.audit_workflow(workflow, &config)?);
+            }
+        }
+        'actions: for action in &actions {
+            for audit in &audits {
+                if budget.is_expired() {
+                    eprintln!("warning: --timeout-secs budget spent; some audits were skipped");
+                    saw_warning = true;
+                    break 'actions;
+                }
+                findings.extend(audit.audit_action(action, &config)?);
+            }
+        }
+        if !budget.is_expired() {
+            for audit in &audits {
+                findings.extend(audit.audit_workflow_set(&workflows, &config)?);
+            }
+        }
+        findings.sort();
+        findings
+    } else if args.timings {
+        let mut report = zizmor::timings::TimingReport::default();
+        let findings = zizmor::run_audits_timed(&workflows, &actions, &audits, &config, &mut report)?;
+        eprintln!("{}", report.render_table());
+        eprintln!("{}", report.render_json()?);
+        findings
+    } else if args.cache {
+        let config_fingerprint = match &args.config {
+            Some(path) => std::fs::read_to_string(path).unwrap_or_default(),
+            None => {
+                let default_path = Utf8Path::new("zizmor.yml");
+                if default_path.exists() {
+                    std::fs::read_to_string(default_path).unwrap_or_default()
+                } else {
+                    String::new()
+                }
+            }
+        };
+        zizmor::run_audits_cached(&workflows, &actions, &audits, &config, &zizmor::cache::cache_dir(), &config_fingerprint)?
+    } else {
+        zizmor::run_audits_parallel(&workflows, &actions, &audits, &config)?
+    };
+
+    // --min-severity/--min-confidence only narrow what gets reported;
+    // `findings` (unfiltered) is still what the exit code is computed
+    // from below, so a hidden finding can't silently pass CI.
+    let min_severity: Severity = args.min_severity.map(Into::into).unwrap_or(Severity::Unknown);
+    let min_confidence: zizmor::finding::Confidence = args.min_confidence.map(Into::into).unwrap_or(zizmor::finding::Confidence::Low);
+    let reported_findings: Vec<_> = findings
+        .iter()
+        .filter(|f| f.severity >= min_severity && f.confidence >= min_confidence)
+        .cloned()
+        .collect();
+
+    if args.export_osv {
+        let matches: Vec<_> = workflows
+            .iter()
+            .flat_map(|workflow| zizmor::audit::known_vulnerable_action::matches(workflow, &config))
+            .collect();
+        print!("{}", zizmor::osv::export_matches(&matches)?);
+        return Ok(());
+    }
+
+    let use_color = match args.color {
+        cli::ColorChoice::Always => true,
+        cli::ColorChoice::Never => false,
+        cli::ColorChoice::Auto => std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal(),
+    };
+
+    match args.format {
+        cli::OutputFormat::Plain => {
+            print!("{}", render_plain(&reported_findings, use_color));
+            print!("{}", render_suppressions_appendix(&config));
+        }
+        cli::OutputFormat::Sonar => print!("{}", zizmor::output::render_sonar(&reported_findings)?),
+        cli::OutputFormat::Sarif => print!("{}", zizmor::output::render_sarif(&reported_findings)?),
+        cli::OutputFormat::Json => {
+            let codeowners = zizmor::codeowners::load(Utf8Path::new("."));
+            print!("{}", zizmor::output::render_json(&reported_findings, &codeowners)?);
+        }
+        cli::OutputFormat::Markdown => {
+            let codeowners = zizmor::codeowners::load(Utf8Path::new("."));
+            print!("{}", zizmor::output::render_markdown(&reported_findings, &codeowners));
+        }
+        cli::OutputFormat::Compact => {
+            let raw_by_path = |path: &str| {
+                workflows
+                    .iter()
+                    .find(|w| w.path.as_str() == path)
+                    .map(|w| w.raw.clone())
+                    .or_else(|| actions.iter().find(|a| a.path.as_str() == path).map(|a| a.raw.clone()))
+            };
+            print!("{}", zizmor::output::render_compact(&reported_findings, raw_by_path));
+        }
+    }
+
+    if args.upload_sarif {
+        match zizmor::sarif_upload::detect_target_from_env() {
+            Some(target) => {
+                let sarif = zizmor::output::render_sarif(&reported_findings)?;
+                struct Unresolved;
+                impl zizmor::sarif_upload::SarifUploader for Unresolved {
+                    fn upload(&self, target: &zizmor::sarif_upload::UploadTarget, sarif: &str) -> anyhow::Result<()> {
+                        eprintln!(
+                            "--upload-sarif has no GitHub client wired up yet; would upload {} bytes of SARIF for {}/{}@{} ({})",
+                            sarif.len(),
+                            target.owner,
+                            target.repo,
+                            target.sha,
+                            target.ref_
+                        );
+                        Ok(())
+                    }
+                }
+                Unresolved.upload(&target, &sarif)?;
+            }
+            None => {
+                eprintln!("warning: --upload-sarif could not resolve repo/ref/sha from the environment; are you running in Actions?");
+            }
+        }
+    }
+
+    if let Some(url) = &args.notify {
+        let baseline = match &args.baseline {
+            Some(path) => zizmor::notify::parse_baseline(&std::fs::read_to_string(path)?),
+            None => Default::default(),
+        };
+        let new_findings = zizmor::notify::new_since(&reported_findings, &baseline);
+        let payload = if url.contains("hooks.slack.com") {
+            zizmor::notify::render_slack_payload(&new_findings)
+        } else {
+            zizmor::notify::render_generic_payload(&new_findings)
+        };
+        struct Unresolved;
+        impl zizmor::notify::NotificationSink for Unresolved {
+            fn notify(&self, url: &str, payload: &serde_json::Value) -> anyhow::Result<()> {
+                eprintln!("--notify has no HTTP client wired up yet; would POST to {url}: {payload}");
+                Ok(())
+            }
+        }
+        Unresolved.notify(url, &payload)?;
+    }
+
+    if args.scorecard {
+        for summary in zizmor::scorecard::summarize(&reported_findings) {
+            println!(
+                "{:?}: {} ({} finding(s))",
+                summary.check,
+                if summary.passing { "pass" } else { "fail" },
+                summary.finding_count
+            );
+        }
+    }
+
+    if args.fix {
+        for (input, workflow) in workflow_inputs.iter().zip(&workflows) {
+            let mut fixes = vec![];
+            for audit in &audits {
+                fixes.extend(audit.suggest_fixes(workflow, &config)?);
+            }
+            if fixes.is_empty() {
+                continue;
+            }
+            if args.dry_run {
+                print!("{}", zizmor::fix::as_patch(input.as_str(), &workflow.raw, &fixes)?);
+            } else {
+                let fixed = zizmor::fix::apply(&workflow.raw, &fixes)?;
+                std::fs::write(input, fixed)?;
+            }
+        }
+    }
+
+    if let Some(post_review) = &args.post_review {
+        let pr_ref = if post_review == "auto" {
+            zizmor::review::detect_pr_ref_from_env()
+        } else {
+            zizmor::review::parse_pr_ref(post_review)
+        };
+        match pr_ref {
+            Some((owner, repo, pr)) => {
+                let raw_by_path = |path: &str| {
+                    workflows
+                        .iter()
+                        .find(|w| w.path.as_str() == path)
+                        .map(|w| w.raw.clone())
+                        .or_else(|| actions.iter().find(|a| a.path.as_str() == path).map(|a| a.raw.clone()))
+                };
+                let comments = zizmor::review::render_review_comments(&reported_findings, raw_by_path);
+                struct StdoutPoster;
+                impl zizmor::review::ReviewPoster for StdoutPoster {
+                    fn post(&self, owner: &str, repo: &str, pr: u64, comments: &[zizmor::review::ReviewComment]) -> anyhow::Result<()> {
+                        eprintln!("--post-review has no GitHub client wired up yet; printing the {} comment(s) that would be posted to {owner}/{repo}#{pr} instead:", comments.len());
+                        for comment in comments {
+                            match comment.line {
+                                Some(line) => println!("{}:{line}: {}", comment.path, comment.body),
+                                None => println!("{}: {}", comment.path, comment.body),
+                            }
+                        }
+                        Ok(())
+                    }
+                }
+                StdoutPoster.post(&owner, &repo, pr, &comments)?;
+            }
+            None => {
+                eprintln!("warning: --post-review could not determine owner/repo#pr; pass it explicitly (owner/repo#123)");
+            }
+        }
+    }
+
+    let exit = if args.strict && (!findings.is_empty() || saw_warning) {
+        exit_code::FINDINGS_AT_THRESHOLD
+    } else {
+        exit_code::compute(&findings, Severity::Medium)
+    };
+    std::process::exit(exit);
+}
@@ -0,0 +1,194 @@
+//! Expands `strategy.matrix` into the concrete job configurations GitHub
+//! Actions would actually run, so audits that look at `runs-on`,
+//! container images, or `env:` don't have to skip matrix-expressed
+//! workflows just because the value isn't a literal.
+
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Strategy {
+    pub matrix: Option<serde_yaml::Value>,
+}
+
+/// One concrete assignment of matrix variable names to values.
+pub type MatrixCombination = BTreeMap<String, serde_yaml::Value>;
+
+/// Expands a `strategy.matrix` value into every combination GitHub
+/// Actions would generate, honoring `include`/`exclude`. Returns a
+/// single empty combination if `matrix` isn't a mapping (including when
+/// there's no matrix at all), so callers can always iterate the result.
+pub fn expand(matrix: &serde_yaml::Value) -> Vec<MatrixCombination> {
+    let serde_yaml::Value::Mapping(map) = matrix else {
+        return vec![MatrixCombination::new()];
+    };
+
+    let mut axes: IndexMap<String, Vec<serde_yaml::Value>> = IndexMap::new();
+    let mut include: Vec<serde_yaml::Mapping> = vec![];
+    let mut exclude: Vec<serde_yaml::Mapping> = vec![];
+
+    for (key, value) in map {
+        let Some(key) = key.as_str() else { continue };
+        match key {
+            "include" => include = as_mappings(value),
+            "exclude" => exclude = as_mappings(value),
+            _ => {
+                if let serde_yaml::Value::Sequence(seq) = value {
+                    axes.insert(key.to_string(), seq.clone());
+                }
+            }
+        }
+    }
+
+    let mut combinations = cartesian_product(&axes);
+    combinations.retain(|combo| !exclude.iter().any(|ex| matches(combo, ex)));
+
+    for entry in &include {
+        let axis_keys: BTreeMap<&str, &serde_yaml::Value> = entry
+            .iter()
+            .filter_map(|(k, v)| k.as_str().map(|k| (k, v)))
+            .filter(|(k, _)| axes.contains_key(*k))
+            .collect();
+
+        let mut matched_any = false;
+        for combo in &mut combinations {
+            if !axis_keys.is_empty() && axis_keys.iter().all(|(k, v)| combo.get(*k) == Some(*v)) {
+                matched_any = true;
+                for (k, v) in entry.iter().filter_map(|(k, v)| k.as_str().map(|k| (k, v))) {
+                    combo.insert(k.to_string(), v.clone());
+                }
+            }
+        }
+
+        if !matched_any {
+            let mut combo = MatrixCombination::new();
+            for (k, v) in entry.iter().filter_map(|(k, v)| k.as_str().map(|k| (k, v))) {
+                combo.insert(k.to_string(), v.clone());
+            }
+            combinations.push(combo);
+        }
+    }
+
+    if combinations.is_empty() {
+        combinations.push(MatrixCombination::new());
+    }
+    combinations
+}
+
+/// Resolves a `${{ matrix.<name> }}` reference against a concrete
+/// combination. Values that aren't a bare matrix reference (including
+/// expressions with other text around them) are returned unchanged,
+/// since substituting inside a larger string isn't meaningful here.
+pub fn resolve(value: &serde_yaml::Value, combo: &MatrixCombination) -> serde_yaml::Value {
+    let re = regex::Regex::new(r"^\$\{\{\s*matrix\.([A-Za-z_][A-Za-z0-9_-]*)\s*\}\}$").unwrap();
+    let Some(s) = value.as_str() else { return value.clone() };
+    let Some(captures) = re.captures(s.trim()) else { return value.clone() };
+    combo.get(&captures[1]).cloned().unwrap_or_else(|| value.clone())
+}
+
+/// The concrete `runs-on` values a job can actually execute under, after
+/// expanding its matrix. Falls back to the literal `runs-on` value
+/// (wrapped in a single-element list) when there's no matrix, so callers
+/// don't need a separate no-matrix code path.
+pub fn runs_on_candidates(job: &crate::models::Job) -> Vec<String> {
+    let Some(runs_on) = &job.runs_on else { return vec![] };
+
+    let combos = match job.strategy.as_ref().and_then(|s| s.matrix.as_ref()) {
+        Some(matrix) => expand(matrix),
+        None => vec![MatrixCombination::new()],
+    };
+
+    combos
+        .iter()
+        .filter_map(|combo| resolve(runs_on, combo).as_str().map(str::to_string))
+        .collect()
+}
+
+fn as_mappings(value: &serde_yaml::Value) -> Vec<serde_yaml::Mapping> {
+    match value {
+        serde_yaml::Value::Sequence(seq) => seq.iter().filter_map(|v| v.as_mapping().cloned()).collect(),
+        _ => vec![],
+    }
+}
+
+fn matches(combo: &MatrixCombination, exclude: &serde_yaml::Mapping) -> bool {
+    exclude
+        .iter()
+        .all(|(k, v)| k.as_str().is_some_and(|k| combo.get(k) == Some(v)))
+}
+
+fn cartesian_product(axes: &IndexMap<String, Vec<serde_yaml::Value>>) -> Vec<MatrixCombination> {
+    let mut combinations = vec![MatrixCombination::new()];
+
+    for (key, values) in axes {
+        let mut expanded = vec![];
+        for combo in &combinations {
+            for value in values {
+                let mut next = combo.clone();
+                next.insert(key.clone(), value.clone());
+                expanded.push(next);
+            }
+        }
+        combinations = expanded;
+    }
+
+    combinations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(s: &str) -> serde_yaml::Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn expands_cartesian_product_of_axes() {
+        let matrix = yaml("os: [ubuntu-latest, windows-latest]\nnode: [16, 18]\n");
+        let combos = expand(&matrix);
+        assert_eq!(combos.len(), 4);
+    }
+
+    #[test]
+    fn exclude_removes_matching_combination() {
+        let matrix = yaml(
+            "os: [ubuntu-latest, windows-latest]\nnode: [16, 18]\nexclude:\n  - os: windows-latest\n    node: 16\n",
+        );
+        let combos = expand(&matrix);
+        assert_eq!(combos.len(), 3);
+    }
+
+    #[test]
+    fn include_adds_extra_key_to_matching_combination() {
+        let matrix = yaml(
+            "os: [ubuntu-latest]\ninclude:\n  - os: ubuntu-latest\n    experimental: true\n",
+        );
+        let combos = expand(&matrix);
+        assert_eq!(combos.len(), 1);
+        assert_eq!(combos[0].get("experimental"), Some(&serde_yaml::Value::Bool(true)));
+    }
+
+    #[test]
+    fn include_with_no_matching_axis_adds_new_combination() {
+        let matrix = yaml("os: [ubuntu-latest]\ninclude:\n  - extra: true\n");
+        let combos = expand(&matrix);
+        assert_eq!(combos.len(), 2);
+    }
+
+    #[test]
+    fn resolve_substitutes_matrix_reference() {
+        let mut combo = MatrixCombination::new();
+        combo.insert("os".to_string(), serde_yaml::Value::String("windows-latest".to_string()));
+        let resolved = resolve(&yaml("${{ matrix.os }}"), &combo);
+        assert_eq!(resolved.as_str(), Some("windows-latest"));
+    }
+
+    #[test]
+    fn non_mapping_matrix_yields_single_empty_combination() {
+        let combos = expand(&serde_yaml::Value::Null);
+        assert_eq!(combos, vec![MatrixCombination::new()]);
+    }
+}
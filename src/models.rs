@@ -0,0 +1,553 @@
+//! Typed models over GitHub Actions workflow and action documents.
+//!
+//! These are thin, mostly-owned wrappers over the parsed YAML intended to
+//! give audits an ergonomic API without re-parsing documents themselves.
+
+use camino::{Utf8Path, Utf8PathBuf};
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workflow {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub on: serde_yaml::Value,
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub env: IndexMap<String, String>,
+    #[serde(default)]
+    pub defaults: Option<Defaults>,
+    #[serde(default)]
+    pub concurrency: Option<Concurrency>,
+    pub jobs: IndexMap<String, Job>,
+
+    #[serde(skip)]
+    pub path: Utf8PathBuf,
+    #[serde(skip)]
+    pub raw: String,
+}
+
+/// A `concurrency:` block, which can be the bare group-name shorthand or
+/// the detailed map form with `cancel-in-progress`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Concurrency {
+    Group(String),
+    Detailed(ConcurrencyGroup),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConcurrencyGroup {
+    /// The group name, often an expression like
+    /// `${{ github.workflow }}-${{ github.ref }}` rather than a literal.
+    pub group: String,
+    #[serde(rename = "cancel-in-progress", default)]
+    pub cancel_in_progress: CancelInProgress,
+}
+
+/// `cancel-in-progress:` accepts either a literal bool or an expression
+/// that evaluates to one at runtime.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CancelInProgress {
+    Bool(bool),
+    Expression(String),
+}
+
+impl Default for CancelInProgress {
+    fn default() -> Self {
+        CancelInProgress::Bool(false)
+    }
+}
+
+impl Concurrency {
+    /// The group name, whatever form this block took.
+    pub fn group(&self) -> &str {
+        match self {
+            Concurrency::Group(group) => group,
+            Concurrency::Detailed(detailed) => &detailed.group,
+        }
+    }
+
+    /// Whether in-progress runs sharing this group get cancelled, as a
+    /// [`crate::reachability::Tri`] since `cancel-in-progress` can be an
+    /// unevaluated expression: the shorthand group-only form never
+    /// cancels (GitHub's default), a literal bool is known outright, and
+    /// an expression is left `Unknown`.
+    pub fn cancels_in_progress(&self) -> crate::reachability::Tri {
+        match self {
+            Concurrency::Group(_) => crate::reachability::Tri::False,
+            Concurrency::Detailed(detailed) => match &detailed.cancel_in_progress {
+                CancelInProgress::Bool(true) => crate::reachability::Tri::True,
+                CancelInProgress::Bool(false) => crate::reachability::Tri::False,
+                CancelInProgress::Expression(_) => crate::reachability::Tri::Unknown,
+            },
+        }
+    }
+
+    /// Whether this group name varies per invocation (contains an
+    /// expression) rather than being a constant string every run of this
+    /// workflow/job shares.
+    pub fn is_constant_group(&self) -> bool {
+        !self.group().contains("${{")
+    }
+}
+
+/// The `defaults:` block: settings applied to every `run:` step unless a
+/// job or step overrides them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Defaults {
+    #[serde(default)]
+    pub run: Option<RunDefaults>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RunDefaults {
+    #[serde(default)]
+    pub shell: Option<String>,
+    #[serde(rename = "working-directory", default)]
+    pub working_directory: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Permissions {
+    Base(String),
+    Map(IndexMap<String, String>),
+}
+
+/// `steps` is a plain `Vec<Step>`; audits iterate it by reference
+/// (`job.steps.iter().enumerate()`, as in
+/// [`crate::audit::custom_rule::CustomRuleAudit`] and most other
+/// per-step audits) rather than through a wrapper that would clone the
+/// parent `Job`, so there's no per-step `Job` clone to eliminate here.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Job {
+    #[serde(rename = "runs-on")]
+    pub runs_on: Option<serde_yaml::Value>,
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub steps: Vec<Step>,
+    #[serde(default)]
+    pub needs: Vec<String>,
+    #[serde(default)]
+    pub env: IndexMap<String, String>,
+    #[serde(rename = "timeout-minutes")]
+    pub timeout_minutes: Option<u32>,
+    #[serde(default)]
+    pub outputs: IndexMap<String, String>,
+    #[serde(default)]
+    pub strategy: Option<crate::matrix::Strategy>,
+    #[serde(rename = "if", default)]
+    pub if_: Option<serde_yaml::Value>,
+    /// Present when this job is a reusable-workflow call rather than a
+    /// regular job: `jobs.<id>.uses:` pointing at the callee.
+    #[serde(default)]
+    pub uses: Option<String>,
+    #[serde(default)]
+    pub with: IndexMap<String, serde_yaml::Value>,
+    #[serde(default)]
+    pub secrets: Option<CallSecrets>,
+    #[serde(default)]
+    pub defaults: Option<Defaults>,
+    #[serde(default)]
+    pub concurrency: Option<Concurrency>,
+}
+
+/// The `secrets:` block of a reusable-workflow call: either the
+/// shorthand `secrets: inherit` or an explicit name-to-expression map.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CallSecrets {
+    Inherit(String),
+    Explicit(IndexMap<String, String>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Step {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub uses: Option<String>,
+    pub run: Option<String>,
+    #[serde(default)]
+    pub shell: Option<String>,
+    #[serde(default)]
+    pub with: IndexMap<String, serde_yaml::Value>,
+    #[serde(default)]
+    pub env: IndexMap<String, String>,
+    #[serde(rename = "if", default)]
+    pub if_: Option<serde_yaml::Value>,
+    #[serde(rename = "working-directory", default)]
+    pub working_directory: Option<String>,
+}
+
+fn run_default_shell(defaults: &Option<Defaults>) -> Option<&str> {
+    defaults.as_ref()?.run.as_ref()?.shell.as_deref()
+}
+
+fn run_default_working_directory(defaults: &Option<Defaults>) -> Option<&str> {
+    defaults.as_ref()?.run.as_ref()?.working_directory.as_deref()
+}
+
+/// Resolves the shell a step's `run:` actually executes under, from
+/// `step`/`job` context alone: the step's own `shell:` override, then
+/// the job's `defaults.run.shell`, then the runner's OS default (`pwsh`
+/// on Windows, `bash` everywhere else). Doesn't see workflow-level
+/// `defaults.run.shell`, since this is the resolution the
+/// [`StepContainer`] abstraction can do without a [`Workflow`] in scope;
+/// callers with the whole workflow available should use
+/// [`effective_shell`] for full precedence.
+fn effective_shell_job_local<'a>(job: &'a Job, step: &'a Step) -> &'a str {
+    if let Some(shell) = &step.shell {
+        return shell;
+    }
+    if let Some(shell) = run_default_shell(&job.defaults) {
+        return shell;
+    }
+    let runs_on_windows = crate::matrix::runs_on_candidates(job).iter().any(|s| s.contains("windows"));
+    if runs_on_windows {
+        "pwsh"
+    } else {
+        "bash"
+    }
+}
+
+/// Resolves the shell a step's `run:` actually executes under, honoring
+/// the full `step` -> `job` -> `workflow` `defaults.run.shell`
+/// precedence before falling back to the runner's OS default.
+pub fn effective_shell<'a>(workflow: &'a Workflow, job: &'a Job, step: &'a Step) -> &'a str {
+    if let Some(shell) = &step.shell {
+        return shell;
+    }
+    if let Some(shell) = run_default_shell(&job.defaults) {
+        return shell;
+    }
+    if let Some(shell) = run_default_shell(&workflow.defaults) {
+        return shell;
+    }
+    let runs_on_windows = crate::matrix::runs_on_candidates(job).iter().any(|s| s.contains("windows"));
+    if runs_on_windows {
+        "pwsh"
+    } else {
+        "bash"
+    }
+}
+
+/// Resolves the working directory a step's `run:` actually executes in,
+/// honoring the full `step` -> `job` -> `workflow`
+/// `defaults.run.working-directory` precedence. `None` means the
+/// runner's default, the repository root.
+pub fn effective_working_directory<'a>(workflow: &'a Workflow, job: &'a Job, step: &'a Step) -> Option<&'a str> {
+    step.working_directory
+        .as_deref()
+        .or_else(|| run_default_working_directory(&job.defaults))
+        .or_else(|| run_default_working_directory(&workflow.defaults))
+}
+
+/// Abstracts over the two places a sequence of [`Step`]s can live -
+/// workflow jobs and composite actions - so step-level audits can walk
+/// both without re-implementing their logic per container. Jobs resolve
+/// their default shell from `runs-on`; composite actions run on whatever
+/// hosted the calling job, so they have no `runs-on` of their own. GitHub
+/// accordingly defaults composite `run:` steps to the same
+/// non-Windows-by-default shell as a job that hasn't declared one.
+pub trait StepContainer {
+    fn steps(&self) -> &[Step];
+    fn effective_shell<'a>(&'a self, step: &'a Step) -> &'a str;
+}
+
+impl StepContainer for Job {
+    fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    fn effective_shell<'a>(&'a self, step: &'a Step) -> &'a str {
+        effective_shell_job_local(self, step)
+    }
+}
+
+impl StepContainer for Action {
+    fn steps(&self) -> &[Step] {
+        match &self.runs {
+            ActionRuns::Composite { steps } => steps,
+            _ => &[],
+        }
+    }
+
+    fn effective_shell<'a>(&'a self, step: &'a Step) -> &'a str {
+        step.shell.as_deref().unwrap_or("bash")
+    }
+}
+
+/// A parsed `owner/repo[/path]@ref` action or reusable-workflow reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Uses {
+    pub owner: String,
+    pub repo: String,
+    pub subpath: Option<String>,
+    pub git_ref: String,
+}
+
+impl Uses {
+    /// Parses a `uses:` string, returning `None` if it isn't a
+    /// recognizable `owner/repo[/path]@ref` form (e.g. local `./` actions
+    /// or Docker references - see [`is_local_uses`]/[`is_docker_uses`] -
+    /// or a string that's malformed in some other way, e.g. missing the
+    /// `@ref` suffix - see [`is_malformed_uses`]).
+    ///
+    /// Splits on the *last* `@` rather than the first, so a `git_ref`
+    /// that happens to contain `@` (unusual, but not forbidden) doesn't
+    /// get absorbed into the path; a trailing `/` before the ref (e.g.
+    /// `owner/repo/@ref`) is trimmed rather than producing an empty
+    /// subpath. Owner/repo casing is preserved as written - GitHub
+    /// treats it case-insensitively, so callers that compare against
+    /// configured owners should do the same rather than relying on this
+    /// function to normalize it away.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (path, git_ref) = raw.rsplit_once('@')?;
+        if git_ref.is_empty() {
+            return None;
+        }
+        let path = path.trim_end_matches('/');
+        let mut parts = path.splitn(3, '/');
+        let owner = parts.next()?.to_string();
+        let repo = parts.next()?.to_string();
+        if owner.is_empty() || repo.is_empty() {
+            return None;
+        }
+        let subpath = parts.next().filter(|s| !s.is_empty()).map(str::to_string);
+        Some(Self {
+            owner,
+            repo,
+            subpath,
+            git_ref: git_ref.to_string(),
+        })
+    }
+
+    /// Whether this reference is pinned to a full 40-character commit SHA.
+    pub fn unpinned(&self) -> bool {
+        !(self.git_ref.len() == 40 && self.git_ref.chars().all(|c| c.is_ascii_hexdigit()))
+    }
+
+    pub fn owner_repo(&self) -> String {
+        format!("{}/{}", self.owner, self.repo)
+    }
+}
+
+/// Whether `raw` is a local action reference (`./path` or `../path`),
+/// which `Uses::parse` never handles since it has no owner/repo to
+/// extract.
+pub fn is_local_uses(raw: &str) -> bool {
+    raw.starts_with("./") || raw.starts_with("../")
+}
+
+/// Whether `raw` is a Docker image reference (`docker://...`).
+pub fn is_docker_uses(raw: &str) -> bool {
+    raw.starts_with("docker://")
+}
+
+/// Whether `raw` looks like it was meant to be a hosted
+/// `owner/repo[/path]@ref` action reference but [`Uses::parse`]
+/// couldn't make sense of it - a missing `@ref` suffix, an empty
+/// owner/repo segment, and similar typos - as opposed to a local or
+/// Docker reference, which just isn't that kind of `uses:` at all and
+/// is expected to fail parsing.
+pub fn is_malformed_uses(raw: &str) -> bool {
+    !is_local_uses(raw) && !is_docker_uses(raw) && Uses::parse(raw).is_none()
+}
+
+/// Normalizes the `on:` value (string, sequence, or map form) into the
+/// list of event names it declares.
+pub fn trigger_names(on: &serde_yaml::Value) -> Vec<String> {
+    crate::triggers::Triggers::new(on).events()
+}
+
+/// A declared `on.workflow_call.inputs.<name>` entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowCallInput {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    pub default: Option<serde_yaml::Value>,
+    #[serde(rename = "type", default)]
+    pub input_type: Option<String>,
+}
+
+/// A declared `on.workflow_call.secrets.<name>` entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowCallSecret {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+struct WorkflowCallTrigger {
+    #[serde(default)]
+    inputs: IndexMap<String, WorkflowCallInput>,
+    #[serde(default)]
+    secrets: IndexMap<String, WorkflowCallSecret>,
+}
+
+impl Workflow {
+    pub fn from_file(path: &Utf8Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)?;
+        Self::from_string(path.as_str(), raw)
+    }
+
+    /// Constructs a [`Workflow`] directly from its contents, without
+    /// touching the filesystem. `name` only needs to be a display name,
+    /// not a real path - this is the entry point for embedders, tests,
+    /// and any future mode (an LSP, a `--stdin` flag) that has a
+    /// document's text but not a file on disk to read it from.
+    /// Parses `contents` exactly once, via `serde_yaml` - there's no
+    /// second structural parse to share a position index with. Byte
+    /// spans ([`crate::span::resolve_scalar_span`]) and comments
+    /// ([`Self::comments`]) are both derived from [`Self::raw`]
+    /// directly (string search and a line scanner, respectively)
+    /// rather than from a second document parse.
+    pub fn from_string(name: impl Into<Utf8PathBuf>, contents: impl Into<String>) -> anyhow::Result<Self> {
+        let raw = contents.into();
+        let mut workflow: Workflow = serde_yaml::from_str(&raw)?;
+        workflow.path = name.into();
+        workflow.raw = raw;
+        Ok(workflow)
+    }
+
+    /// This document's comments, parsed fresh from [`Self::raw`] on each
+    /// call. See [`crate::comments`].
+    pub fn comments(&self) -> Vec<crate::comments::Comment> {
+        crate::comments::parse_comments(&self.raw)
+    }
+
+    /// Whether this workflow declares a `workflow_call` trigger, i.e.
+    /// can be invoked as a reusable workflow.
+    pub fn is_reusable(&self) -> bool {
+        self.workflow_call_trigger().is_some()
+    }
+
+    pub fn workflow_call_inputs(&self) -> IndexMap<String, WorkflowCallInput> {
+        self.workflow_call_trigger().map(|t| t.inputs).unwrap_or_default()
+    }
+
+    pub fn workflow_call_secrets(&self) -> IndexMap<String, WorkflowCallSecret> {
+        self.workflow_call_trigger().map(|t| t.secrets).unwrap_or_default()
+    }
+
+    fn workflow_call_trigger(&self) -> Option<WorkflowCallTrigger> {
+        let value = match &self.on {
+            serde_yaml::Value::Mapping(map) => map.get("workflow_call")?,
+            serde_yaml::Value::String(s) if s == "workflow_call" => return Some(WorkflowCallTrigger::default()),
+            serde_yaml::Value::Sequence(seq) if seq.iter().any(|v| v.as_str() == Some("workflow_call")) => {
+                return Some(WorkflowCallTrigger::default())
+            }
+            _ => return None,
+        };
+        if value.is_null() {
+            return Some(WorkflowCallTrigger::default());
+        }
+        serde_yaml::from_value(value.clone()).ok()
+    }
+}
+
+/// A parsed `action.yml`/`action.yaml` definition: the analogue of
+/// [`Workflow`] for a single action rather than a whole workflow.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Action {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub inputs: IndexMap<String, ActionInput>,
+    #[serde(default)]
+    pub outputs: IndexMap<String, ActionOutput>,
+    #[serde(default)]
+    pub branding: Option<Branding>,
+    pub runs: ActionRuns,
+
+    #[serde(skip)]
+    pub path: Utf8PathBuf,
+    #[serde(skip)]
+    pub raw: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionInput {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    pub default: Option<String>,
+    #[serde(rename = "deprecationMessage")]
+    pub deprecation_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionOutput {
+    pub description: Option<String>,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Branding {
+    pub icon: Option<String>,
+    pub color: Option<String>,
+}
+
+/// The `runs:` block, tagged by `using:`. Covers composite, the
+/// currently-supported Node runtimes, and Docker actions.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "using")]
+pub enum ActionRuns {
+    #[serde(rename = "composite")]
+    Composite { steps: Vec<Step> },
+    #[serde(rename = "node12")]
+    Node12 {
+        main: String,
+        pre: Option<String>,
+        post: Option<String>,
+    },
+    #[serde(rename = "node16")]
+    Node16 {
+        main: String,
+        pre: Option<String>,
+        post: Option<String>,
+    },
+    #[serde(rename = "node20")]
+    Node20 {
+        main: String,
+        pre: Option<String>,
+        post: Option<String>,
+    },
+    #[serde(rename = "docker")]
+    Docker {
+        image: String,
+        entrypoint: Option<String>,
+        args: Option<Vec<String>>,
+    },
+}
+
+impl Action {
+    pub fn from_file(path: &Utf8Path) -> anyhow::Result<Self> {
+        let raw = std::fs::read_to_string(path)?;
+        Self::from_string(path.as_str(), raw)
+    }
+
+    /// Constructs an [`Action`] directly from its contents, without
+    /// touching the filesystem. Same rationale as
+    /// [`Workflow::from_string`]: embedders, tests, and any future
+    /// filesystem-free mode need this as their entry point.
+    pub fn from_string(name: impl Into<Utf8PathBuf>, contents: impl Into<String>) -> anyhow::Result<Self> {
+        let raw = contents.into();
+        let mut action: Action = serde_yaml::from_str(&raw)?;
+        action.path = name.into();
+        action.raw = raw;
+        Ok(action)
+    }
+
+    /// This document's comments, parsed fresh from [`Self::raw`] on each
+    /// call. See [`crate::comments`].
+    pub fn comments(&self) -> Vec<crate::comments::Comment> {
+        crate::comments::parse_comments(&self.raw)
+    }
+}
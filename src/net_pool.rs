@@ -0,0 +1,80 @@
+//! Bounds how many resolver calls run at once, and gives a resolver a
+//! place to share connection state across calls.
+//!
+//! A real async HTTP client (`reqwest`/`hyper` on a `tokio` runtime,
+//! with pooled connections and per-request timeouts) is out of scope
+//! for this crate - it doesn't vendor an async runtime, and converting
+//! every [`crate::audit::Audit`] to `async fn` just for the handful of
+//! online audits isn't worth the blast radius. What's achievable today
+//! with only `rayon` (already a dependency, see
+//! [`crate::run_audits_parallel`]) is a thread-based concurrency cap:
+//! [`bounded_map`] runs a resolver call per item with at most
+//! [`NetworkPolicy::max_concurrency`] in flight, which is the part of
+//! "bounded, pooled, concurrent" that actually matters for a scan with
+//! hundreds of distinct `uses:` references - a real implementation
+//! would pair it with an HTTP client it constructs once and clones into
+//! each call, so TCP/TLS connections get reused across the batch.
+
+use std::time::Duration;
+
+/// How many outstanding resolver calls are allowed at once, and the
+/// per-call timeout a resolver is expected to honor. Distinct from
+/// `--pre-commit`'s [`crate::pre_commit::Budget`], which bounds total
+/// audit wall-time rather than per-call concurrency.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkPolicy {
+    pub max_concurrency: usize,
+    pub per_request_timeout: Duration,
+}
+
+impl Default for NetworkPolicy {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 8,
+            per_request_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Calls `f` once per item in `items`, at most `policy.max_concurrency`
+/// at a time, returning results in `items`' order.
+pub fn bounded_map<T: Sync, R: Send>(items: &[T], policy: NetworkPolicy, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    use rayon::prelude::*;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(policy.max_concurrency.max(1))
+        .build()
+        .expect("failed to build bounded resolver thread pool");
+    pool.install(|| items.par_iter().map(&f).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn preserves_input_order() {
+        let items = vec![1, 2, 3, 4, 5];
+        let results = bounded_map(&items, NetworkPolicy::default(), |n| n * 10);
+        assert_eq!(results, vec![10, 20, 30, 40, 50]);
+    }
+
+    #[test]
+    fn never_exceeds_max_concurrency() {
+        let items = vec![(); 32];
+        let policy = NetworkPolicy {
+            max_concurrency: 2,
+            ..NetworkPolicy::default()
+        };
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        bounded_map(&items, policy, |_| {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(current, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(5));
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        });
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+}
@@ -0,0 +1,110 @@
+//! Posts a summary of findings to a generic webhook or Slack-compatible
+//! endpoint, for teams that track CI security drift outside of zizmor's
+//! own output.
+
+use std::collections::HashSet;
+
+use crate::finding::{Finding, Severity};
+
+/// A finding's identity for baseline comparison: its rule id and
+/// severity, matching [`Finding`]'s own `PartialEq`.
+pub type FindingKey = (String, String);
+
+fn key(finding: &Finding) -> FindingKey {
+    (finding.ident.to_string(), finding.severity.to_string())
+}
+
+/// Parses a baseline set of finding keys out of a previous `--format
+/// json` run. Any JSON that isn't an array of objects with `ident`/
+/// `severity` fields yields an empty baseline rather than an error, so a
+/// malformed or stale baseline file just treats every finding as new.
+pub fn parse_baseline(raw: &str) -> HashSet<FindingKey> {
+    let Ok(serde_json::Value::Array(entries)) = serde_json::from_str(raw) else {
+        return HashSet::new();
+    };
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let ident = entry.get("ident")?.as_str()?.to_string();
+            let severity = entry.get("severity")?.as_str()?.to_string();
+            Some((ident, severity))
+        })
+        .collect()
+}
+
+/// The findings in `findings` that aren't present in `baseline`, i.e.
+/// newly introduced since the baseline was captured. Everything is new
+/// against an empty baseline.
+pub fn new_since<'a>(findings: &'a [Finding], baseline: &HashSet<FindingKey>) -> Vec<&'a Finding> {
+    findings.iter().filter(|finding| !baseline.contains(&key(finding))).collect()
+}
+
+/// Builds the JSON payload posted to a generic (non-Slack) webhook.
+pub fn render_generic_payload(new_findings: &[&Finding]) -> serde_json::Value {
+    serde_json::json!({
+        "new_finding_count": new_findings.len(),
+        "findings": new_findings.iter().map(|finding| serde_json::json!({
+            "ident": finding.ident,
+            "severity": finding.severity,
+            "desc": finding.desc,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// Builds a Slack-compatible `{"text": ...}` payload summarizing
+/// `new_findings` by severity, for posting to an Incoming Webhook URL.
+pub fn render_slack_payload(new_findings: &[&Finding]) -> serde_json::Value {
+    if new_findings.is_empty() {
+        return serde_json::json!({ "text": "zizmor: no new findings." });
+    }
+
+    let mut by_severity: Vec<(Severity, usize)> = vec![];
+    for finding in new_findings {
+        match by_severity.iter_mut().find(|(severity, _)| *severity == finding.severity) {
+            Some((_, count)) => *count += 1,
+            None => by_severity.push((finding.severity, 1)),
+        }
+    }
+    by_severity.sort_by_key(|(severity, _)| std::cmp::Reverse(*severity));
+
+    let summary = by_severity.iter().map(|(severity, count)| format!("{count} {severity}")).collect::<Vec<_>>().join(", ");
+    serde_json::json!({ "text": format!("zizmor: {} new finding(s) ({summary})", new_findings.len()) })
+}
+
+/// Posts a rendered payload to an external webhook/Slack endpoint.
+/// Implementations own the actual HTTP request.
+pub trait NotificationSink {
+    fn notify(&self, url: &str, payload: &serde_json::Value) -> anyhow::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finding::Confidence;
+
+    fn finding(ident: &'static str, severity: Severity) -> Finding {
+        Finding::new(ident, "test").with_severity(severity).with_confidence(Confidence::High)
+    }
+
+    #[test]
+    fn new_since_excludes_baseline_matches() {
+        let findings = vec![finding("unpinned-uses", Severity::High), finding("excessive-permissions", Severity::Medium)];
+        let baseline = parse_baseline(r#"[{"ident": "unpinned-uses", "severity": "high"}]"#);
+        let new_findings = new_since(&findings, &baseline);
+        assert_eq!(new_findings.len(), 1);
+        assert_eq!(new_findings[0].ident, "excessive-permissions");
+    }
+
+    #[test]
+    fn malformed_baseline_treats_everything_as_new() {
+        let findings = vec![finding("unpinned-uses", Severity::High)];
+        let baseline = parse_baseline("not json");
+        assert_eq!(new_since(&findings, &baseline).len(), 1);
+    }
+
+    #[test]
+    fn empty_new_findings_renders_no_new_findings_text() {
+        let payload = render_slack_payload(&[]);
+        assert_eq!(payload["text"], "zizmor: no new findings.");
+    }
+}
@@ -0,0 +1,85 @@
+//! Org-wide scanning: iterating every repository under an owner and
+//! auditing its workflows with the right config in effect.
+
+use crate::audit::Audit;
+use crate::config::Config;
+use crate::finding::Finding;
+use crate::models::Workflow;
+
+/// One repository discovered during an org scan.
+pub struct RepoTarget {
+    pub owner: String,
+    pub repo: String,
+}
+
+/// Resolves the config to use for `repo`: the repo's own `zizmor.yml`
+/// (if any) merged underneath the invoking/central config, so repo-level
+/// suppressions apply without overriding central policy decisions the
+/// central config didn't delegate.
+///
+/// `repo_config_contents` is `None` when the repo has no `zizmor.yml` of
+/// its own, in which case the central config is used unmodified.
+pub fn effective_config_for(central: &Config, repo_config_contents: Option<&str>) -> anyhow::Result<Config> {
+    let Some(contents) = repo_config_contents else {
+        return Ok(central.clone());
+    };
+    let repo_config = Config::from_str(contents)?;
+    Ok(central.clone().merge_repo_overlay(repo_config))
+}
+
+/// Discovers every repository under an org, one at a time, rather than
+/// returning the full list up front - a real implementation paginates
+/// the GitHub API, and [`scan_streaming`] only ever needs one
+/// [`RepoTarget`] in hand at a time anyway.
+pub trait RepoSource {
+    /// Calls `f` once per repository. An org with thousands of repos
+    /// never needs to be held in memory as a `Vec<RepoTarget>`.
+    fn for_each_repo(&self, f: &mut dyn FnMut(RepoTarget) -> anyhow::Result<()>) -> anyhow::Result<()>;
+}
+
+/// Fetches one repository's workflow files - and its own `zizmor.yml`,
+/// if any - on demand, right before that repository is audited.
+pub trait WorkflowSource {
+    /// `(path, content)` for each workflow file in `repo`.
+    fn workflows_for(&self, repo: &RepoTarget) -> anyhow::Result<Vec<(String, String)>>;
+    /// `repo`'s own `zizmor.yml` contents, per [`effective_config_for`].
+    fn repo_config_for(&self, repo: &RepoTarget) -> anyhow::Result<Option<String>>;
+}
+
+/// Receives one repository's findings immediately after they're
+/// computed, so [`scan_streaming`] never has to accumulate every
+/// repository's findings for the whole org before handing them off.
+pub trait FindingSink {
+    fn emit(&mut self, repo: &RepoTarget, findings: &[Finding]) -> anyhow::Result<()>;
+}
+
+/// Scans every repository under an org as a streaming pipeline: fetch a
+/// repository's workflows, audit them, emit the findings, then drop
+/// them before moving to the next repository. At most one repository's
+/// workflows and findings are resident in memory at once, instead of
+/// loading the whole org up front - the difference between scanning
+/// thousands of repos on a laptop and running out of memory trying to.
+pub fn scan_streaming(
+    central: &Config,
+    repos: &dyn RepoSource,
+    source: &dyn WorkflowSource,
+    audits: &[Box<dyn Audit>],
+    sink: &mut dyn FindingSink,
+) -> anyhow::Result<()> {
+    repos.for_each_repo(&mut |repo| {
+        let repo_config_contents = source.repo_config_for(&repo)?;
+        let config = effective_config_for(central, repo_config_contents.as_deref())?;
+
+        let mut findings = vec![];
+        for (path, content) in source.workflows_for(&repo)? {
+            let workflow = Workflow::from_string(path, content)?;
+            for audit in audits {
+                findings.extend(audit.audit_workflow(&workflow, &config)?);
+            }
+        }
+        findings.sort();
+        sink.emit(&repo, &findings)
+        // `workflow`/`findings` drop here, before the next repository's
+        // data is fetched.
+    })
+}
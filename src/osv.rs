@@ -0,0 +1,125 @@
+//! A minimal [OSV](https://ossf.github.io/osv-schema/) reader/writer for
+//! feeds of known-vulnerable or malicious GitHub Actions.
+//!
+//! This only models the subset of the schema `known-vulnerable-action`
+//! actually needs: an advisory id/summary and the exact refs/SHAs of an
+//! `owner/repo` it applies to. OSV's range-based versioning doesn't map
+//! cleanly onto Actions (there's no ecosystem-wide ordering of git
+//! refs), so affected versions are matched as an exact-string allowlist
+//! rather than evaluated as a range.
+//!
+//! Feeds are loaded from a local file only; fetching a *remote* feed
+//! safely needs the same offline-safe cache [`crate::config`]'s
+//! `extends: org/repo` form is waiting on, so that's left as a
+//! follow-up.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Package {
+    pub name: String,
+    #[serde(default)]
+    pub ecosystem: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AffectedPackage {
+    pub package: Package,
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Advisory {
+    pub id: String,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub affected: Vec<AffectedPackage>,
+}
+
+/// Loads advisories from OSV-format JSON: either a bare `[...]` array of
+/// advisories, or the batch `{"vulns": [...]}` wrapper OSV's own export
+/// API uses.
+pub fn load_advisories(json: &str) -> anyhow::Result<Vec<Advisory>> {
+    #[derive(Deserialize)]
+    struct Feed {
+        vulns: Vec<Advisory>,
+    }
+    if let Ok(feed) = serde_json::from_str::<Feed>(json) {
+        return Ok(feed.vulns);
+    }
+    Ok(serde_json::from_str(json)?)
+}
+
+/// Finds the advisory (if any) covering `git_ref` of `owner_repo`.
+pub fn find_advisory<'a>(advisories: &'a [Advisory], owner_repo: &str, git_ref: &str) -> Option<&'a Advisory> {
+    advisories.iter().find(|advisory| {
+        advisory
+            .affected
+            .iter()
+            .any(|affected| affected.package.name.eq_ignore_ascii_case(owner_repo) && affected.versions.iter().any(|v| v == git_ref))
+    })
+}
+
+/// One `(owner/repo@ref, advisory)` match, ready to render either as a
+/// [`crate::finding::Finding`] (by the `known-vulnerable-action` audit)
+/// or re-exported as its own OSV document (by `zizmor export-osv`).
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub owner_repo: String,
+    pub git_ref: String,
+    pub advisory: Advisory,
+}
+
+/// Re-exports a set of matches as an OSV batch document, for
+/// aggregation with other scanners' output.
+pub fn export_matches(matches: &[Match]) -> anyhow::Result<String> {
+    #[derive(Serialize)]
+    struct Feed<'a> {
+        vulns: Vec<&'a Advisory>,
+    }
+    let feed = Feed {
+        vulns: matches.iter().map(|m| &m.advisory).collect(),
+    };
+    Ok(serde_json::to_string_pretty(&feed)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_feed() -> &'static str {
+        r#"{
+            "vulns": [
+                {
+                    "id": "GHSA-xxxx",
+                    "summary": "backdoored release",
+                    "affected": [
+                        {"package": {"name": "evil/action", "ecosystem": "GitHub Actions"}, "versions": ["v1", "deadbeef"]}
+                    ]
+                }
+            ]
+        }"#
+    }
+
+    #[test]
+    fn loads_batch_wrapped_feed() {
+        let advisories = load_advisories(sample_feed()).unwrap();
+        assert_eq!(advisories.len(), 1);
+        assert_eq!(advisories[0].id, "GHSA-xxxx");
+    }
+
+    #[test]
+    fn loads_bare_array_feed() {
+        let advisories = load_advisories(r#"[{"id": "GHSA-yyyy", "summary": "", "affected": []}]"#).unwrap();
+        assert_eq!(advisories.len(), 1);
+    }
+
+    #[test]
+    fn finds_advisory_by_exact_ref() {
+        let advisories = load_advisories(sample_feed()).unwrap();
+        assert!(find_advisory(&advisories, "evil/action", "v1").is_some());
+        assert!(find_advisory(&advisories, "evil/action", "v2").is_none());
+    }
+}
@@ -0,0 +1,220 @@
+//! Rendering of findings to the terminal, and to other tools' report
+//! formats.
+
+use crate::codeowners::{self, Entry as CodeownersEntry};
+use crate::config::Config;
+use crate::finding::{Finding, Severity};
+
+/// Renders every active suppression with its justification, for audit
+/// trails when a report needs to explain why findings are missing.
+pub fn render_suppressions_appendix(config: &Config) -> String {
+    if config.ignore.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\nActive suppressions:\n");
+    for entry in &config.ignore {
+        let reason = entry.reason.as_deref().unwrap_or("(no reason given)");
+        out.push_str(&format!("  - {}: {}\n", entry.rule, reason));
+    }
+    out
+}
+
+fn ansi_color_for(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "\x1b[31m",
+        Severity::Medium => "\x1b[33m",
+        Severity::Low => "\x1b[36m",
+        Severity::Informational | Severity::Unknown => "\x1b[90m",
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Renders findings as plain ASCII text - no unicode box-drawing or
+/// emoji, so it's safe for restricted CI consoles and for capturing to
+/// a file. `color` additionally wraps the severity/ident prefix in an
+/// ANSI color escape per [`Severity`]; the CLI resolves `color` from
+/// `--color`/`NO_COLOR` before calling this.
+pub fn render_plain(findings: &[Finding], color: bool) -> String {
+    let mut out = String::new();
+    for finding in findings {
+        for location in &finding.locations {
+            let prefix = format!("{severity}[{ident}]", severity = finding.severity, ident = finding.ident);
+            let prefix = if color {
+                format!("{}{prefix}{ANSI_RESET}", ansi_color_for(finding.severity))
+            } else {
+                prefix
+            };
+            out.push_str(&format!("{prefix}: {desc} --> {path}\n", desc = finding.desc, path = location.path));
+        }
+    }
+    out
+}
+
+fn sonar_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High => "CRITICAL",
+        Severity::Medium => "MAJOR",
+        Severity::Low => "MINOR",
+        Severity::Informational | Severity::Unknown => "INFO",
+    }
+}
+
+/// Renders `findings` as SonarQube's [Generic Issue Import] JSON, one
+/// issue per finding location, so orgs standardized on Sonar dashboards
+/// can track zizmor findings alongside their other static analysis
+/// results. Every zizmor rule is a security-relevant check, so each
+/// issue is typed `VULNERABILITY` rather than `BUG`/`CODE_SMELL`.
+///
+/// [Generic Issue Import]: https://docs.sonarsource.com/sonarqube/latest/analyzing-source-code/importing-external-issues/generic-issue-import-format/
+pub fn render_sonar(findings: &[Finding]) -> anyhow::Result<String> {
+    let issues: Vec<_> = findings
+        .iter()
+        .flat_map(|finding| {
+            finding.locations.iter().map(move |location| {
+                serde_json::json!({
+                    "engineId": "zizmor",
+                    "ruleId": finding.ident,
+                    "severity": sonar_severity(finding.severity),
+                    "type": "VULNERABILITY",
+                    "primaryLocation": {
+                        "message": finding.desc,
+                        "filePath": location.path,
+                    },
+                })
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&serde_json::json!({ "issues": issues }))?)
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::High | Severity::Medium => "error",
+        Severity::Low => "warning",
+        Severity::Informational | Severity::Unknown => "note",
+    }
+}
+
+/// Renders `findings` as a minimal SARIF 2.1.0 log with one `zizmor`
+/// run, for GitHub code scanning (`--upload-sarif`) and other
+/// SARIF-consuming tools. Every distinct rule id used by `findings`
+/// gets one `rules[]` entry.
+pub fn render_sarif(findings: &[Finding]) -> anyhow::Result<String> {
+    let mut rule_ids: Vec<&str> = findings.iter().map(|f| f.ident).collect();
+    rule_ids.sort_unstable();
+    rule_ids.dedup();
+    let rules: Vec<_> = rule_ids.iter().map(|id| serde_json::json!({ "id": id })).collect();
+
+    let results: Vec<_> = findings
+        .iter()
+        .flat_map(|finding| {
+            finding.locations.iter().map(move |location| {
+                serde_json::json!({
+                    "ruleId": finding.ident,
+                    "level": sarif_level(finding.severity),
+                    "message": { "text": finding.desc },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": location.path },
+                        },
+                    }],
+                })
+            })
+        })
+        .collect();
+
+    let document = serde_json::json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": { "name": "zizmor", "rules": rules } },
+            "results": results,
+        }],
+    });
+    Ok(serde_json::to_string_pretty(&document)?)
+}
+
+/// Renders `findings` as JSON, with each location annotated with its
+/// `CODEOWNERS` owners (if `codeowners` is non-empty) for routing in
+/// large-org scans.
+pub fn render_json(findings: &[Finding], codeowners: &[CodeownersEntry]) -> anyhow::Result<String> {
+    let annotated: Vec<_> = findings
+        .iter()
+        .map(|finding| {
+            let locations: Vec<_> = finding
+                .locations
+                .iter()
+                .map(|location| {
+                    let owners = codeowners::owners_for(codeowners, location.path.as_str()).unwrap_or_default();
+                    serde_json::json!({
+                        "path": location.path,
+                        "route": location.route.to_string(),
+                        "annotation": location.annotation,
+                        "owners": owners,
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "ident": finding.ident,
+                "desc": finding.desc,
+                "severity": finding.severity,
+                "confidence": finding.confidence,
+                "locations": locations,
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&annotated)?)
+}
+
+/// 1-indexed (line, column) of byte offset `pos` within `raw`.
+fn line_col_of(raw: &str, pos: usize) -> (usize, usize) {
+    let pos = pos.min(raw.len());
+    let line = raw[..pos].matches('\n').count() + 1;
+    let col = pos - raw[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0) + 1;
+    (line, col)
+}
+
+/// Renders `findings` one line per location, `file:line:col:
+/// severity[rule]: message`, stable enough for an editor problem
+/// matcher or a grep pipeline. `raw_by_path` looks up a file's raw
+/// source by its [`crate::finding::SymbolicLocation::path`] to resolve
+/// a byte span into a line/column; locations without a resolvable span
+/// fall back to `1:1`.
+pub fn render_compact(findings: &[Finding], raw_by_path: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::new();
+    for finding in findings {
+        for location in &finding.locations {
+            let (line, col) = location
+                .span
+                .as_ref()
+                .and_then(|span| raw_by_path(location.path.as_str()).map(|raw| line_col_of(&raw, span.start)))
+                .unwrap_or((1, 1));
+            out.push_str(&format!(
+                "{path}:{line}:{col}: {severity}[{ident}]: {desc}\n",
+                path = location.path,
+                severity = finding.severity,
+                ident = finding.ident,
+                desc = finding.desc,
+            ));
+        }
+    }
+    out
+}
+
+/// Renders `findings` as a Markdown table, with a CODEOWNERS column
+/// when `codeowners` is non-empty, for posting as a PR/issue comment or
+/// a CI job summary.
+pub fn render_markdown(findings: &[Finding], codeowners: &[CodeownersEntry]) -> String {
+    let mut out = String::from("| Severity | Rule | Location | Owners | Description |\n|---|---|---|---|---|\n");
+    for finding in findings {
+        for location in &finding.locations {
+            let owners = codeowners::owners_for(codeowners, location.path.as_str()).unwrap_or_default().join(", ");
+            out.push_str(&format!(
+                "| {} | `{}` | {} | {} | {} |\n",
+                finding.severity, finding.ident, location.path, owners, finding.desc
+            ));
+        }
+    }
+    out
+}
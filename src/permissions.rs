@@ -0,0 +1,153 @@
+//! Computes a job's effective `GITHUB_TOKEN` permissions by merging
+//! workflow-level, job-level, and GitHub's own defaults, including the
+//! read-only downgrade GitHub applies to `pull_request` workflows that
+//! can run against a forked repository's code.
+
+use indexmap::IndexMap;
+
+use crate::models::{trigger_names, Permissions, Workflow};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub enum Level {
+    None,
+    Read,
+    Write,
+}
+
+/// GitHub's own default token permissions when a workflow declares no
+/// `permissions:` block anywhere: either the legacy "permissive" default
+/// (write access to most scopes) or the newer repository/org-level
+/// "restricted" default (read-only).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultPermissions {
+    Permissive,
+    Restricted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub enum EffectivePermissions {
+    Base(Level),
+    Scoped(IndexMap<String, Level>),
+}
+
+impl EffectivePermissions {
+    pub fn get(&self, scope: &str) -> Level {
+        match self {
+            EffectivePermissions::Base(level) => *level,
+            EffectivePermissions::Scoped(map) => map.get(scope).copied().unwrap_or(Level::None),
+        }
+    }
+
+    pub fn grants_write(&self, scope: &str) -> bool {
+        self.get(scope) == Level::Write
+    }
+}
+
+fn base_level(s: &str) -> Level {
+    match s {
+        "write-all" => Level::Write,
+        "read-all" => Level::Read,
+        _ => Level::None,
+    }
+}
+
+fn scope_level(s: &str) -> Level {
+    match s {
+        "write" => Level::Write,
+        "read" => Level::Read,
+        _ => Level::None,
+    }
+}
+
+fn from_model(permissions: &Permissions) -> EffectivePermissions {
+    match permissions {
+        Permissions::Base(s) => EffectivePermissions::Base(base_level(s)),
+        Permissions::Map(map) => EffectivePermissions::Scoped(map.iter().map(|(k, v)| (k.clone(), scope_level(v))).collect()),
+    }
+}
+
+/// The effective permissions for `job_id` before any fork downgrade: the
+/// job's own `permissions:` block if present, else the workflow's, else
+/// GitHub's default. A `permissions:` block fully replaces whatever it
+/// shadows rather than merging key-by-key, matching GitHub's own
+/// behavior.
+pub fn resolve(workflow: &Workflow, job_id: &str, default: DefaultPermissions) -> EffectivePermissions {
+    if let Some(permissions) = workflow.jobs.get(job_id).and_then(|job| job.permissions.as_ref()) {
+        return from_model(permissions);
+    }
+    if let Some(permissions) = &workflow.permissions {
+        return from_model(permissions);
+    }
+    EffectivePermissions::Base(match default {
+        DefaultPermissions::Permissive => Level::Write,
+        DefaultPermissions::Restricted => Level::Read,
+    })
+}
+
+/// Whether `workflow` can run against a forked repository's code with a
+/// token GitHub would downgrade to read-only, regardless of declared
+/// permissions. Only plain `pull_request` triggers this;
+/// `pull_request_target` runs with the base repository's permissions and
+/// isn't downgraded.
+pub fn forkable(workflow: &Workflow) -> bool {
+    trigger_names(&workflow.on).iter().any(|t| t == "pull_request")
+}
+
+/// As [`resolve`], but applies GitHub's fork read-only downgrade when
+/// `workflow` is fork-triggerable.
+pub fn resolve_worst_case(workflow: &Workflow, job_id: &str, default: DefaultPermissions) -> EffectivePermissions {
+    let permissions = resolve(workflow, job_id, default);
+    if !forkable(workflow) {
+        return permissions;
+    }
+    match permissions {
+        EffectivePermissions::Base(level) => EffectivePermissions::Base(level.min(Level::Read)),
+        EffectivePermissions::Scoped(map) => {
+            EffectivePermissions::Scoped(map.into_iter().map(|(k, v)| (k, v.min(Level::Read))).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workflow(yaml: &str) -> Workflow {
+        Workflow::from_string("w.yml", yaml).unwrap()
+    }
+
+    #[test]
+    fn job_permissions_shadow_workflow_permissions() {
+        let workflow = workflow(
+            "on: push\npermissions: write-all\njobs:\n  j:\n    permissions:\n      contents: read\n    runs-on: ubuntu-latest\n    steps: []\n",
+        );
+        let resolved = resolve(&workflow, "j", DefaultPermissions::Restricted);
+        assert_eq!(resolved.get("contents"), Level::Read);
+        assert!(!resolved.grants_write("contents"));
+    }
+
+    #[test]
+    fn falls_back_to_github_default_when_unset() {
+        let workflow = workflow("on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n");
+        let resolved = resolve(&workflow, "j", DefaultPermissions::Permissive);
+        assert_eq!(resolved.get("contents"), Level::Write);
+    }
+
+    #[test]
+    fn pull_request_downgrades_write_to_read() {
+        let workflow = workflow(
+            "on: pull_request\npermissions: write-all\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        );
+        let resolved = resolve_worst_case(&workflow, "j", DefaultPermissions::Restricted);
+        assert_eq!(resolved.get("contents"), Level::Read);
+    }
+
+    #[test]
+    fn pull_request_target_is_not_downgraded() {
+        let workflow = workflow(
+            "on: pull_request_target\npermissions: write-all\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        );
+        let resolved = resolve_worst_case(&workflow, "j", DefaultPermissions::Restricted);
+        assert_eq!(resolved.get("contents"), Level::Write);
+    }
+}
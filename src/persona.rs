@@ -0,0 +1,27 @@
+//! Built-in personas that control which audits run by default and how
+//! loud they are.
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// A coarse profile selecting which audits are active.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Default, Serialize)]
+#[clap(rename_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum Persona {
+    /// Low-noise defaults for everyday contributors.
+    #[default]
+    Regular,
+    /// Everything `regular` runs, plus stricter/more opinionated checks.
+    Pedantic,
+    /// Every heuristic, including low-confidence ones, for dedicated
+    /// security review.
+    Auditor,
+}
+
+impl Persona {
+    /// Whether an audit declaring `min_persona` should run under `self`.
+    pub fn includes(self, min_persona: Persona) -> bool {
+        self >= min_persona
+    }
+}
@@ -0,0 +1,55 @@
+//! WASM plugin interface for third-party audits.
+//!
+//! A plugin is a `wasm32-wasi` module exporting a single function:
+//!
+//! ```text
+//! fn audit(workflow_ir_json_ptr: i32, workflow_ir_json_len: i32) -> i32
+//! ```
+//!
+//! which receives the serialized [`crate::models::Workflow`] IR and
+//! returns a pointer/length-encoded JSON array of [`crate::finding::Finding`]s
+//! (the exact calling convention is documented in `docs/plugins.md`).
+//! This module only defines the host-side interface; invocation lives
+//! behind the `plugins` feature so offline/WASM builds of zizmor itself
+//! don't need to pull in a WASM runtime.
+
+use camino::Utf8PathBuf;
+use serde::Deserialize;
+
+/// One `plugins:` entry in config.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PluginSpec {
+    /// Rule id namespace this plugin's findings are reported under,
+    /// e.g. `acme-internal`.
+    pub id: String,
+    /// Path to the compiled `.wasm` module.
+    pub path: Utf8PathBuf,
+}
+
+/// A loaded plugin, ready to be invoked per workflow.
+///
+/// The actual WASM host (instantiation, memory marshalling) is only
+/// compiled in with the `plugins` feature, since it pulls in a
+/// non-trivial runtime dependency that offline/minimal builds shouldn't
+/// pay for.
+#[cfg(feature = "plugins")]
+pub struct LoadedPlugin {
+    pub spec: PluginSpec,
+}
+
+#[cfg(feature = "plugins")]
+impl LoadedPlugin {
+    pub fn load(spec: PluginSpec) -> anyhow::Result<Self> {
+        anyhow::ensure!(spec.path.exists(), "plugin module not found: {}", spec.path);
+        Ok(Self { spec })
+    }
+
+    /// Runs the plugin against a workflow's IR and parses its findings.
+    ///
+    /// Left unimplemented pending the `wasmtime` integration tracked in
+    /// the plugin RFC; callers should treat plugin findings as additive
+    /// and non-fatal if a plugin module fails to load or run.
+    pub fn audit(&self, _workflow_ir_json: &str) -> anyhow::Result<Vec<crate::finding::Finding>> {
+        anyhow::bail!("WASM plugin execution is not yet implemented for {}", self.spec.id)
+    }
+}
@@ -0,0 +1,72 @@
+//! Support for `--pre-commit`: a mode tuned for running on every commit,
+//! where a fast, predictable no-op matters more than exhaustive
+//! coverage. Skips network audits by default and caps total audit time
+//! so a hook is never the reason a commit is slow.
+//!
+//! A real implementation would also reuse a persistent result cache
+//! keyed by file content hash, so files that haven't changed since the
+//! last run skip re-auditing entirely - but that depends on a cache
+//! subsystem this crate doesn't have yet (the same gap
+//! [`crate::config`] already flags needing "an offline-safe cache" for
+//! trusted-publisher lookups), so this mode only covers the pieces that
+//! are actionable without one: forcing audits offline and bounding
+//! wall-clock time.
+
+use std::time::{Duration, Instant};
+
+/// Whether online audits should be skipped, given `--pre-commit`, the
+/// already-explicit `--offline` flag, and whether a GitHub token was
+/// explicitly supplied - which counts as opting back into network
+/// access even under `--pre-commit`.
+pub fn effective_offline(pre_commit: bool, offline: bool, gh_token: Option<&str>) -> bool {
+    offline || (pre_commit && gh_token.is_none())
+}
+
+/// Tracks elapsed time against a fixed budget, so a caller running many
+/// audits in a loop can bail out early once the budget is spent instead
+/// of finishing a thorough-but-slow pass.
+pub struct Budget {
+    deadline: Instant,
+}
+
+impl Budget {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + duration,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pre_commit_forces_offline_without_an_explicit_token() {
+        assert!(effective_offline(true, false, None));
+    }
+
+    #[test]
+    fn explicit_token_opts_back_into_online_audits() {
+        assert!(!effective_offline(true, false, Some("ghp_x")));
+    }
+
+    #[test]
+    fn offline_flag_alone_still_forces_offline() {
+        assert!(effective_offline(false, true, None));
+    }
+
+    #[test]
+    fn fresh_budget_is_not_expired() {
+        assert!(!Budget::new(Duration::from_secs(5)).is_expired());
+    }
+
+    #[test]
+    fn zero_budget_is_immediately_expired() {
+        assert!(Budget::new(Duration::from_secs(0)).is_expired());
+    }
+}
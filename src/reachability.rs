@@ -0,0 +1,172 @@
+//! Partial evaluation of `if:` conditions against a workflow's declared
+//! triggers, so audits can tell which steps and jobs are statically dead
+//! (never run under any trigger) or unconditional (run under every
+//! trigger), without having to understand the full expression language.
+//! Anything that depends on runtime state - job status functions,
+//! context fields we can't see at analysis time - folds to [`Tri::Unknown`]
+//! rather than guessing.
+
+use crate::expr::{self, Expr};
+use crate::models::trigger_names;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tri {
+    True,
+    False,
+    Unknown,
+}
+
+impl Tri {
+    fn not(self) -> Tri {
+        match self {
+            Tri::True => Tri::False,
+            Tri::False => Tri::True,
+            Tri::Unknown => Tri::Unknown,
+        }
+    }
+
+    fn and(self, other: Tri) -> Tri {
+        match (self, other) {
+            (Tri::False, _) | (_, Tri::False) => Tri::False,
+            (Tri::True, Tri::True) => Tri::True,
+            _ => Tri::Unknown,
+        }
+    }
+
+    fn or(self, other: Tri) -> Tri {
+        match (self, other) {
+            (Tri::True, _) | (_, Tri::True) => Tri::True,
+            (Tri::False, Tri::False) => Tri::False,
+            _ => Tri::Unknown,
+        }
+    }
+}
+
+/// Strips a `${{ ... }}` wrapper if present; bare `if:` conditions (e.g.
+/// `github.ref == 'refs/heads/main'`) are already a raw expression.
+fn expr_text(raw: &str) -> &str {
+    let trimmed = raw.trim();
+    match trimmed.strip_prefix("${{").and_then(|s| s.strip_suffix("}}")) {
+        Some(inner) => inner.trim(),
+        None => trimmed,
+    }
+}
+
+/// Evaluates an `if:` condition as far as possible given the workflow's
+/// `on:` triggers. `None` (no `if:` at all) is always reachable.
+pub fn eval_if(if_value: Option<&serde_yaml::Value>, on: &serde_yaml::Value) -> Tri {
+    let Some(value) = if_value else { return Tri::True };
+    match value {
+        serde_yaml::Value::Bool(b) => {
+            if *b {
+                Tri::True
+            } else {
+                Tri::False
+            }
+        }
+        serde_yaml::Value::String(s) => match expr::parse(expr_text(s)) {
+            Ok(expr) => fold(&expr, &trigger_names(on)),
+            Err(_) => Tri::Unknown,
+        },
+        _ => Tri::Unknown,
+    }
+}
+
+fn fold(expr: &Expr, triggers: &[String]) -> Tri {
+    match expr {
+        Expr::Ident(name) if name == "true" => Tri::True,
+        Expr::Ident(name) if name == "false" => Tri::False,
+        Expr::Call(name, args) if name == "always" && args.is_empty() => Tri::True,
+        Expr::BinOp(op, lhs, rhs) => match op.as_str() {
+            "&&" => fold(lhs, triggers).and(fold(rhs, triggers)),
+            "||" => fold(lhs, triggers).or(fold(rhs, triggers)),
+            "==" => fold_event_name_eq(lhs, rhs, triggers).unwrap_or(Tri::Unknown),
+            "!=" => fold_event_name_eq(lhs, rhs, triggers).map(Tri::not).unwrap_or(Tri::Unknown),
+            _ => Tri::Unknown,
+        },
+        _ => Tri::Unknown,
+    }
+}
+
+/// Folds `github.event_name == '<literal>'` (in either operand order)
+/// against the workflow's triggers: `False` if the literal names an
+/// event the workflow never fires on, `True` if it's the workflow's
+/// *only* trigger, `Unknown` if the workflow fires on that event among
+/// others (reachable, but not unconditionally).
+fn fold_event_name_eq(lhs: &Expr, rhs: &Expr, triggers: &[String]) -> Option<Tri> {
+    let literal = match (is_event_name(lhs), is_event_name(rhs)) {
+        (true, _) => as_str_literal(rhs)?,
+        (_, true) => as_str_literal(lhs)?,
+        _ => return None,
+    };
+    if !triggers.iter().any(|t| t == literal) {
+        Some(Tri::False)
+    } else if triggers.len() == 1 {
+        Some(Tri::True)
+    } else {
+        Some(Tri::Unknown)
+    }
+}
+
+fn is_event_name(expr: &Expr) -> bool {
+    matches!(expr, Expr::Member(base, field) if field == "event_name" && matches!(base.as_ref(), Expr::Ident(i) if i == "github"))
+}
+
+fn as_str_literal(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Str(s) => Some(s),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn on(triggers: &str) -> serde_yaml::Value {
+        serde_yaml::from_str(triggers).unwrap()
+    }
+
+    #[test]
+    fn no_if_is_always_reachable() {
+        assert_eq!(eval_if(None, &on("push")), Tri::True);
+    }
+
+    #[test]
+    fn always_call_is_always_true() {
+        let value = serde_yaml::Value::String("${{ always() }}".to_string());
+        assert_eq!(eval_if(Some(&value), &on("push")), Tri::True);
+    }
+
+    #[test]
+    fn event_name_never_matched_is_unreachable() {
+        let value = serde_yaml::Value::String("github.event_name == 'pull_request'".to_string());
+        assert_eq!(eval_if(Some(&value), &on("push")), Tri::False);
+    }
+
+    #[test]
+    fn event_name_as_sole_trigger_is_unconditional() {
+        let value = serde_yaml::Value::String("github.event_name == 'push'".to_string());
+        assert_eq!(eval_if(Some(&value), &on("push")), Tri::True);
+    }
+
+    #[test]
+    fn event_name_among_other_triggers_is_unknown() {
+        let value = serde_yaml::Value::String("github.event_name == 'push'".to_string());
+        assert_eq!(eval_if(Some(&value), &on("[push, pull_request]")), Tri::Unknown);
+    }
+
+    #[test]
+    fn unsupported_functions_fold_to_unknown() {
+        let value = serde_yaml::Value::String("${{ failure() }}".to_string());
+        assert_eq!(eval_if(Some(&value), &on("push")), Tri::Unknown);
+    }
+
+    #[test]
+    fn conjunction_short_circuits_on_false() {
+        let value = serde_yaml::Value::String(
+            "github.event_name == 'pull_request' && failure()".to_string(),
+        );
+        assert_eq!(eval_if(Some(&value), &on("push")), Tri::False);
+    }
+}
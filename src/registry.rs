@@ -0,0 +1,110 @@
+//! Central registry of built-in audits.
+
+use crate::audit::cache_poisoning::CachePoisoning;
+use crate::audit::checkout_persist_credentials::CheckoutPersistCredentials;
+use crate::audit::custom_rule::CustomRuleAudit;
+use crate::audit::dangerous_triggers::DangerousTriggers;
+use crate::audit::deprecated_commands::DeprecatedCommands;
+use crate::audit::deprecated_runner_image::DeprecatedRunnerImage;
+use crate::audit::env_references::EnvReferences;
+use crate::audit::excessive_permissions::ExcessivePermissions;
+use crate::audit::external_secrets_inherit::ExternalSecretsInherit;
+use crate::audit::invalid_event_context::InvalidEventContext;
+use crate::audit::job_graph::JobGraphAudit;
+use crate::audit::known_vulnerable_action::KnownVulnerableAction;
+use crate::audit::missing_permissions::MissingPermissions;
+use crate::audit::missing_timeout::MissingTimeout;
+use crate::audit::overbroad_concurrency::OverbroadConcurrency;
+use crate::audit::pin_comment::PinComment;
+use crate::audit::pull_request_target_checkout::PullRequestTargetCheckout;
+use crate::audit::reusable_workflow_call::ReusableWorkflowCall;
+use crate::audit::script_rule::ScriptRuleAudit;
+use crate::audit::secret_in_logs::SecretInLogs;
+use crate::audit::secrets_to_unpinned_uses::SecretsToUnpinnedUses;
+use crate::audit::step_references::StepReferences;
+use crate::audit::template_injection::TemplateInjection;
+use crate::audit::unpinned_uses::UnpinnedUses;
+use crate::audit::unreachable_code::UnreachableCode;
+use crate::audit::workflow_run_artifact::WorkflowRunArtifactPoisoning;
+use crate::audit::Audit;
+use crate::config::Config;
+
+/// All built-in rule ids, used by config validation to catch typos in
+/// `ignore`/`severity-overrides` entries.
+pub const KNOWN_RULE_IDS: &[&str] = &[
+    "cache-poisoning",
+    "external-secrets-inherit",
+    "missing-permissions",
+    "unpinned-uses",
+    "excessive-permissions",
+    "dangerous-triggers",
+    "missing-timeout",
+    "deprecated-commands",
+    "deprecated-runner-image",
+    "checkout-persist-credentials",
+    "template-injection",
+    "job-dependency-graph",
+    "reusable-workflow-call",
+    "unreachable-code",
+    "invalid-event-context",
+    "overbroad-concurrency",
+    "secret-in-logs",
+    "invalid-step-reference",
+    "env-reference",
+    "pin-comment-mismatch",
+    "known-vulnerable-action",
+    "pull-request-target-checkout",
+    "secrets-to-unpinned-uses",
+    "workflow-run-artifact-poisoning",
+];
+
+/// Builds the built-in set of audits, in the order they're run.
+pub fn default_audits() -> Vec<Box<dyn Audit>> {
+    vec![
+        Box::new(CachePoisoning),
+        Box::new(ExternalSecretsInherit),
+        Box::new(MissingPermissions),
+        Box::new(UnpinnedUses::new()),
+        Box::new(ExcessivePermissions),
+        Box::new(DangerousTriggers),
+        Box::new(MissingTimeout),
+        Box::new(DeprecatedCommands),
+        Box::new(DeprecatedRunnerImage),
+        Box::new(CheckoutPersistCredentials),
+        Box::new(TemplateInjection),
+        Box::new(JobGraphAudit),
+        Box::new(ReusableWorkflowCall),
+        Box::new(UnreachableCode),
+        Box::new(InvalidEventContext),
+        Box::new(OverbroadConcurrency),
+        Box::new(SecretInLogs),
+        Box::new(StepReferences),
+        Box::new(EnvReferences),
+        Box::new(PinComment),
+        Box::new(KnownVulnerableAction),
+        Box::new(PullRequestTargetCheckout),
+        Box::new(SecretsToUnpinnedUses),
+        Box::new(WorkflowRunArtifactPoisoning),
+    ]
+}
+
+/// Builds the full set of audits for a run: the built-ins plus one
+/// [`CustomRuleAudit`] per rule declared in `config.custom_rules`.
+pub fn audits_for(config: &Config) -> Vec<Box<dyn Audit>> {
+    let mut audits = default_audits();
+    audits.extend(
+        config
+            .custom_rules
+            .iter()
+            .cloned()
+            .map(|rule| Box::new(CustomRuleAudit::new(rule)) as Box<dyn Audit>),
+    );
+    audits.extend(
+        config
+            .scripts
+            .iter()
+            .cloned()
+            .map(|spec| Box::new(ScriptRuleAudit::new(spec)) as Box<dyn Audit>),
+    );
+    audits
+}
@@ -0,0 +1,124 @@
+//! Binds a reusable-workflow call site (`jobs.<id>.uses:` plus its
+//! `with:`/`secrets:`) to the callee's declared `workflow_call` inputs
+//! and secrets, when both caller and callee are in the same scan set.
+
+use camino::Utf8Path;
+
+use crate::models::{CallSecrets, Job, Workflow};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BindingIssue {
+    MissingRequiredInput { name: String },
+    UnknownInput { name: String },
+    UnknownSecret { name: String },
+    MissingRequiredSecret { name: String },
+}
+
+/// Resolves a `jobs.<id>.uses:` value to the callee [`Workflow`] among
+/// `scan_set`, if it points at a local reusable workflow (`./...`) that
+/// was actually scanned. Remote (`owner/repo/...@ref`) callees aren't
+/// resolvable without fetching, so callers of this function should treat
+/// `None` as "can't check", not "not reusable".
+pub fn resolve_callee<'a>(caller_path: &Utf8Path, call: &str, scan_set: &'a [Workflow]) -> Option<&'a Workflow> {
+    let relative = call.strip_prefix("./")?;
+    let caller_dir = caller_path.parent()?;
+    let resolved = caller_dir.join(relative);
+    scan_set.iter().find(|w| w.path == resolved)
+}
+
+/// Checks a single reusable-workflow call job against its callee's
+/// declared `workflow_call` inputs/secrets.
+pub fn check_binding(job: &Job, callee: &Workflow) -> Vec<BindingIssue> {
+    let mut issues = vec![];
+    let declared_inputs = callee.workflow_call_inputs();
+    let declared_secrets = callee.workflow_call_secrets();
+
+    for (name, input) in &declared_inputs {
+        if input.required && input.default.is_none() && !job.with.contains_key(name) {
+            issues.push(BindingIssue::MissingRequiredInput { name: name.clone() });
+        }
+    }
+    for name in job.with.keys() {
+        if !declared_inputs.contains_key(name) {
+            issues.push(BindingIssue::UnknownInput { name: name.clone() });
+        }
+    }
+
+    match &job.secrets {
+        None => {
+            for (name, secret) in &declared_secrets {
+                if secret.required {
+                    issues.push(BindingIssue::MissingRequiredSecret { name: name.clone() });
+                }
+            }
+        }
+        // `secrets: inherit` forwards everything from the caller's own
+        // context, so there's nothing to bind-check here.
+        Some(CallSecrets::Inherit(_)) => {}
+        Some(CallSecrets::Explicit(passed)) => {
+            for (name, secret) in &declared_secrets {
+                if secret.required && !passed.contains_key(name) {
+                    issues.push(BindingIssue::MissingRequiredSecret { name: name.clone() });
+                }
+            }
+            for name in passed.keys() {
+                if !declared_secrets.contains_key(name) {
+                    issues.push(BindingIssue::UnknownSecret { name: name.clone() });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_missing_required_input() {
+        let callee = Workflow::from_string(
+            "callee.yml",
+            "on:\n  workflow_call:\n    inputs:\n      environment:\n        required: true\n        type: string\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n",
+        )
+        .unwrap();
+        let caller = Workflow::from_string(
+            "caller.yml",
+            "on: push\njobs:\n  call:\n    uses: ./callee.yml\n",
+        )
+        .unwrap();
+        let job = &caller.jobs["call"];
+
+        let issues = check_binding(job, &callee);
+        assert!(issues.contains(&BindingIssue::MissingRequiredInput { name: "environment".to_string() }));
+    }
+
+    #[test]
+    fn flags_unknown_input() {
+        let callee = Workflow::from_string("callee.yml", "on: workflow_call\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n").unwrap();
+        let caller = Workflow::from_string(
+            "caller.yml",
+            "on: push\njobs:\n  call:\n    uses: ./callee.yml\n    with:\n      typo: true\n",
+        )
+        .unwrap();
+        let job = &caller.jobs["call"];
+
+        let issues = check_binding(job, &callee);
+        assert!(issues.contains(&BindingIssue::UnknownInput { name: "typo".to_string() }));
+    }
+
+    #[test]
+    fn resolves_local_callee_from_scan_set() {
+        let callee = Workflow::from_string(".github/workflows/callee.yml", "on: workflow_call\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps: []\n").unwrap();
+        let caller = Workflow::from_string(
+            ".github/workflows/caller.yml",
+            "on: push\njobs:\n  call:\n    uses: ./callee.yml\n",
+        )
+        .unwrap();
+        let scan_set = vec![callee];
+
+        let resolved = resolve_callee(&caller.path, "./callee.yml", &scan_set);
+        assert!(resolved.is_some());
+    }
+}
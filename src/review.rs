@@ -0,0 +1,148 @@
+//! Turns [`Finding`]s into inline PR review comments, and defines the
+//! seam a real GitHub API client plugs into for `--post-review`.
+//!
+//! Posting a review comment needs a concrete byte-offset-to-line
+//! mapping and a network client; this module builds the comment bodies
+//! and the trait a real poster implements, the same way
+//! [`crate::audit::unpinned_uses::RefResolver`] keeps SHA resolution
+//! behind a trait so the rest of the audit stays usable offline.
+
+use crate::finding::{Finding, SymbolicLocation};
+
+/// A single inline review comment, shaped for GitHub's "create a review
+/// comment" API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewComment {
+    pub path: String,
+    /// 1-indexed line within the file. `None` when the finding carries
+    /// no byte span to resolve one from, in which case a real poster
+    /// should fall back to a top-of-file or summary comment instead of
+    /// an inline one.
+    pub line: Option<usize>,
+    pub body: String,
+}
+
+/// 1-indexed line number of byte offset `pos` within `raw`.
+fn line_of(raw: &str, pos: usize) -> usize {
+    raw[..pos.min(raw.len())].matches('\n').count() + 1
+}
+
+/// The `zizmor.yml` snippet that would silence this exact finding,
+/// appended to every review comment so a reviewer can act on it
+/// without leaving the PR.
+fn suppression_instructions(finding: &Finding, location: &SymbolicLocation) -> String {
+    format!(
+        "To silence this finding, add to `zizmor.yml`:\n```yaml\nignore:\n  - rule: {}\n    workflow: {}\n    reason: \"<why this is a false positive or accepted risk>\"\n```",
+        finding.ident, location.path
+    )
+}
+
+/// Builds one review comment per finding location. `raw_by_path` looks
+/// up a file's raw source by its [`SymbolicLocation::path`], used to
+/// resolve a byte span into a line number when one is available.
+pub fn render_review_comments(findings: &[Finding], raw_by_path: impl Fn(&str) -> Option<String>) -> Vec<ReviewComment> {
+    let mut comments = vec![];
+    for finding in findings {
+        for location in &finding.locations {
+            let line = location
+                .span
+                .as_ref()
+                .and_then(|span| raw_by_path(location.path.as_str()).map(|raw| line_of(&raw, span.start)));
+            comments.push(ReviewComment {
+                path: location.path.to_string(),
+                line,
+                body: format!(
+                    "**{severity} [{ident}]**: {desc}\n\n{suppression}",
+                    severity = finding.severity,
+                    ident = finding.ident,
+                    desc = finding.desc,
+                    suppression = suppression_instructions(finding, location),
+                ),
+            });
+        }
+    }
+    comments
+}
+
+/// Posts rendered comments to a pull request. A real implementation
+/// calls the GitHub REST API
+/// (`POST /repos/{owner}/{repo}/pulls/{pr}/reviews`); kept behind a
+/// trait, the same as [`crate::audit::unpinned_uses::RefResolver`], so
+/// `--post-review` stays testable and this crate doesn't have to vendor
+/// an HTTP client just to build.
+pub trait ReviewPoster {
+    fn post(&self, owner: &str, repo: &str, pr: u64, comments: &[ReviewComment]) -> anyhow::Result<()>;
+}
+
+/// Parses an explicit `owner/repo#123` reference, as passed to
+/// `--post-review`.
+pub fn parse_pr_ref(s: &str) -> Option<(String, String, u64)> {
+    let (repo, number) = s.rsplit_once('#')?;
+    let (owner, repo) = repo.split_once('/')?;
+    let number = number.parse().ok()?;
+    Some((owner.to_string(), repo.to_string(), number))
+}
+
+/// Best-effort auto-detection of the current PR when running inside
+/// GitHub Actions, so `--post-review` can be used with no explicit ref
+/// in a workflow. Reads `GITHUB_REPOSITORY` (`owner/repo`) and pulls the
+/// PR number out of `GITHUB_REF` (`refs/pull/<number>/merge`).
+pub fn detect_pr_ref_from_env() -> Option<(String, String, u64)> {
+    let repo = std::env::var("GITHUB_REPOSITORY").ok()?;
+    let (owner, repo) = repo.split_once('/')?;
+    let ghref = std::env::var("GITHUB_REF").ok()?;
+    let number: u64 = ghref.strip_prefix("refs/pull/")?.split('/').next()?.parse().ok()?;
+    Some((owner.to_string(), repo.to_string(), number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finding::{Confidence, Route, Severity, SymbolicLocation};
+
+    #[test]
+    fn parses_explicit_pr_ref() {
+        assert_eq!(
+            parse_pr_ref("jsoref/zizmor#123"),
+            Some(("jsoref".to_string(), "zizmor".to_string(), 123))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_pr_ref() {
+        assert_eq!(parse_pr_ref("not-a-ref"), None);
+    }
+
+    #[test]
+    fn renders_one_comment_per_location_with_suppression_instructions() {
+        let finding = Finding::new("missing-timeout", "job has no timeout-minutes set")
+            .with_severity(Severity::Low)
+            .with_confidence(Confidence::High)
+            .with_location(SymbolicLocation {
+                path: "w.yml".into(),
+                route: Route::job("build"),
+                annotation: "job has no timeout".into(),
+                span: None,
+            });
+        let comments = render_review_comments(&[finding], |_| None);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].line, None);
+        assert!(comments[0].body.contains("missing-timeout"));
+        assert!(comments[0].body.contains("zizmor.yml"));
+    }
+
+    #[test]
+    fn resolves_line_number_from_span() {
+        let finding = Finding::new("missing-timeout", "job has no timeout-minutes set")
+            .with_severity(Severity::Low)
+            .with_confidence(Confidence::High)
+            .with_location(SymbolicLocation {
+                path: "w.yml".into(),
+                route: Route::job("build"),
+                annotation: "job has no timeout".into(),
+                span: Some(10..20),
+            });
+        let comments = render_review_comments(&[finding], |_| Some("a: 1\nb: 2\nc: 3\n".to_string()));
+        assert_eq!(comments[0].line, Some(3));
+    }
+}
@@ -0,0 +1,55 @@
+//! Machine-readable listing of every built-in audit, for `zizmor rules`
+//! and for wrappers/UIs that want to build a rule picker without
+//! scraping the docs or CLI help text.
+
+use serde::Serialize;
+
+use crate::persona::Persona;
+use crate::registry::default_audits;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleInfo {
+    pub ident: &'static str,
+    pub persona: Persona,
+    pub needs_network: bool,
+    /// Every built-in rule can be silenced or have its severity
+    /// overridden via `ignore`/`severity-overrides` in `zizmor.yml`;
+    /// there's no further per-rule configuration surface today.
+    pub configurable: bool,
+}
+
+/// Lists every built-in audit. Excludes `CustomRuleAudit`/`ScriptRuleAudit`
+/// instances, which are generated from `zizmor.yml` rather than built in
+/// and so have no fixed [`Audit::ident`] to report here.
+pub fn list() -> Vec<RuleInfo> {
+    default_audits()
+        .iter()
+        .map(|audit| RuleInfo {
+            ident: audit.ident(),
+            persona: audit.persona(),
+            needs_network: audit.needs_network(),
+            configurable: true,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::KNOWN_RULE_IDS;
+
+    #[test]
+    fn lists_every_known_rule() {
+        let idents: Vec<_> = list().into_iter().map(|rule| rule.ident).collect();
+        for known in KNOWN_RULE_IDS {
+            assert!(idents.contains(known), "missing {known} from rules::list()");
+        }
+    }
+
+    #[test]
+    fn unpinned_uses_needs_network() {
+        let rules = list();
+        let unpinned = rules.iter().find(|r| r.ident == "unpinned-uses").unwrap();
+        assert!(unpinned.needs_network);
+    }
+}
@@ -0,0 +1,95 @@
+//! Knowledge base of GitHub-hosted runner labels: their OS, architecture,
+//! and whether GitHub has announced or completed deprecation. Exposed so
+//! audits that look at `runs-on` - EOL images, OS-specific shell
+//! semantics - share one table instead of pattern-matching label strings
+//! themselves.
+//!
+//! Self-hosted labels (anything not in [`TABLE`]) are unmodeled, not
+//! flagged, since this knowledge base only covers GitHub-hosted images.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Os {
+    Linux,
+    Windows,
+    MacOs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arch {
+    X64,
+    Arm64,
+}
+
+struct Entry {
+    label: &'static str,
+    os: Os,
+    arch: Arch,
+    /// Set once GitHub has announced or completed removal of this image.
+    deprecated: bool,
+}
+
+const TABLE: &[Entry] = &[
+    Entry { label: "ubuntu-latest", os: Os::Linux, arch: Arch::X64, deprecated: false },
+    Entry { label: "ubuntu-24.04", os: Os::Linux, arch: Arch::X64, deprecated: false },
+    Entry { label: "ubuntu-22.04", os: Os::Linux, arch: Arch::X64, deprecated: false },
+    Entry { label: "ubuntu-20.04", os: Os::Linux, arch: Arch::X64, deprecated: true },
+    Entry { label: "ubuntu-24.04-arm", os: Os::Linux, arch: Arch::Arm64, deprecated: false },
+    Entry { label: "ubuntu-22.04-arm", os: Os::Linux, arch: Arch::Arm64, deprecated: false },
+    Entry { label: "windows-latest", os: Os::Windows, arch: Arch::X64, deprecated: false },
+    Entry { label: "windows-2025", os: Os::Windows, arch: Arch::X64, deprecated: false },
+    Entry { label: "windows-2022", os: Os::Windows, arch: Arch::X64, deprecated: false },
+    Entry { label: "windows-2019", os: Os::Windows, arch: Arch::X64, deprecated: true },
+    Entry { label: "macos-latest", os: Os::MacOs, arch: Arch::Arm64, deprecated: false },
+    Entry { label: "macos-15", os: Os::MacOs, arch: Arch::Arm64, deprecated: false },
+    Entry { label: "macos-14", os: Os::MacOs, arch: Arch::Arm64, deprecated: false },
+    Entry { label: "macos-13", os: Os::MacOs, arch: Arch::X64, deprecated: false },
+    Entry { label: "macos-12", os: Os::MacOs, arch: Arch::X64, deprecated: true },
+    Entry { label: "macos-11", os: Os::MacOs, arch: Arch::X64, deprecated: true },
+];
+
+fn lookup(label: &str) -> Option<&'static Entry> {
+    TABLE.iter().find(|e| e.label == label)
+}
+
+/// The OS a known GitHub-hosted runner label boots, or `None` for
+/// self-hosted or unmodeled labels.
+pub fn os_of(label: &str) -> Option<Os> {
+    lookup(label).map(|e| e.os)
+}
+
+/// The architecture a known GitHub-hosted runner label boots, or `None`
+/// for self-hosted or unmodeled labels.
+pub fn arch_of(label: &str) -> Option<Arch> {
+    lookup(label).map(|e| e.arch)
+}
+
+/// Whether `label` is a GitHub-hosted image that's been deprecated
+/// (announced or completed removal). Always `false` for self-hosted or
+/// unmodeled labels, since this knowledge base has nothing to say about
+/// those.
+pub fn is_deprecated(label: &str) -> bool {
+    lookup(label).is_some_and(|e| e.deprecated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_label_resolves_os_and_arch() {
+        assert_eq!(os_of("windows-2022"), Some(Os::Windows));
+        assert_eq!(arch_of("ubuntu-24.04-arm"), Some(Arch::Arm64));
+    }
+
+    #[test]
+    fn deprecated_image_is_flagged() {
+        assert!(is_deprecated("macos-11"));
+        assert!(!is_deprecated("macos-14"));
+    }
+
+    #[test]
+    fn self_hosted_label_is_unmodeled_not_deprecated() {
+        assert_eq!(os_of("self-hosted"), None);
+        assert!(!is_deprecated("self-hosted"));
+    }
+}
@@ -0,0 +1,69 @@
+//! Defines the seam a real GitHub client plugs into for
+//! `--upload-sarif`, the same deferred-network pattern as
+//! [`crate::audit::unpinned_uses::RefResolver`]/[`crate::review::ReviewPoster`].
+//! Uploading needs `POST /repos/{owner}/{repo}/code-scanning/sarifs`
+//! with the SARIF payload gzip+base64-encoded; this crate doesn't
+//! vendor an HTTP client just to build, so that call stays behind a
+//! trait the CLI wires up when one is available.
+
+/// The repo/ref/commit a SARIF upload targets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadTarget {
+    pub owner: String,
+    pub repo: String,
+    pub ref_: String,
+    pub sha: String,
+}
+
+/// Resolves the current repo/ref/commit from the environment variables
+/// GitHub Actions sets on every run, so `--upload-sarif` needs no flags
+/// when invoked from a workflow.
+pub fn detect_target_from_env() -> Option<UploadTarget> {
+    let repo = std::env::var("GITHUB_REPOSITORY").ok()?;
+    let (owner, repo) = repo.split_once('/')?;
+    let ref_ = std::env::var("GITHUB_REF").ok()?;
+    let sha = std::env::var("GITHUB_SHA").ok()?;
+    Some(UploadTarget {
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+        ref_,
+        sha,
+    })
+}
+
+pub trait SarifUploader {
+    fn upload(&self, target: &UploadTarget, sarif: &str) -> anyhow::Result<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn detects_target_from_actions_env() {
+        std::env::set_var("GITHUB_REPOSITORY", "octo/repo");
+        std::env::set_var("GITHUB_REF", "refs/heads/main");
+        std::env::set_var("GITHUB_SHA", "abc123");
+        assert_eq!(
+            detect_target_from_env(),
+            Some(UploadTarget {
+                owner: "octo".to_string(),
+                repo: "repo".to_string(),
+                ref_: "refs/heads/main".to_string(),
+                sha: "abc123".to_string(),
+            })
+        );
+        std::env::remove_var("GITHUB_REPOSITORY");
+        std::env::remove_var("GITHUB_REF");
+        std::env::remove_var("GITHUB_SHA");
+    }
+
+    #[test]
+    #[serial]
+    fn missing_env_returns_none() {
+        std::env::remove_var("GITHUB_REPOSITORY");
+        assert_eq!(detect_target_from_env(), None);
+    }
+}
@@ -0,0 +1,132 @@
+//! Inventories the external dependencies a set of workflows pulls in -
+//! actions, reusable workflow calls, and Docker-image references - for
+//! `zizmor sbom`'s CycloneDX/SPDX output. A job's own `container:` image
+//! (as opposed to a step's `uses: docker://...`) isn't modeled yet (see
+//! [`crate::models::Job`]), so it isn't represented here either; this is
+//! the same kind of honest, documented gap as `config.rs`'s unimplemented
+//! `extends:` cache.
+
+use serde::Serialize;
+
+use crate::models::{is_docker_uses, Uses, Workflow};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ComponentKind {
+    Action,
+    ReusableWorkflow,
+    ContainerImage,
+}
+
+/// A single external dependency referenced by a workflow's `uses:`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Component {
+    pub kind: ComponentKind,
+    /// `owner/repo[/subpath]` for actions and reusable workflows, or the
+    /// bare image name (without the `docker://` prefix) for containers.
+    pub name: String,
+    /// The pinned SHA or tag for actions/workflows, or the image
+    /// tag/digest for containers; `"latest"` if a container reference
+    /// has neither.
+    pub version: String,
+}
+
+fn is_reusable_workflow_subpath(subpath: Option<&str>) -> bool {
+    subpath.is_some_and(|subpath| subpath.contains(".github/workflows/"))
+}
+
+fn uses_component(raw: &str) -> Option<Component> {
+    if is_docker_uses(raw) {
+        let image = raw.trim_start_matches("docker://");
+        let (name, version) = image.rsplit_once(':').unwrap_or((image, "latest"));
+        return Some(Component {
+            kind: ComponentKind::ContainerImage,
+            name: name.to_string(),
+            version: version.to_string(),
+        });
+    }
+
+    let uses = Uses::parse(raw)?;
+    let kind = if is_reusable_workflow_subpath(uses.subpath.as_deref()) {
+        ComponentKind::ReusableWorkflow
+    } else {
+        ComponentKind::Action
+    };
+    let name = match &uses.subpath {
+        Some(subpath) => format!("{}/{subpath}", uses.owner_repo()),
+        None => uses.owner_repo(),
+    };
+    Some(Component { kind, name, version: uses.git_ref })
+}
+
+/// Every external dependency referenced by `workflow`'s jobs (reusable
+/// workflow calls) and steps (actions, reusable workflow calls, Docker
+/// images).
+pub fn components(workflow: &Workflow) -> Vec<Component> {
+    let mut components = vec![];
+
+    for job in workflow.jobs.values() {
+        if let Some(raw) = &job.uses {
+            components.extend(uses_component(raw));
+        }
+        for step in &job.steps {
+            let Some(raw) = &step.uses else { continue };
+            components.extend(uses_component(raw));
+        }
+    }
+
+    components
+}
+
+/// Deduplicates `components` by `(kind, name, version)`, preserving
+/// first-seen order, for a stable SBOM across a multi-workflow scan.
+pub fn dedupe(components: Vec<Component>) -> Vec<Component> {
+    let mut seen = std::collections::HashSet::new();
+    components
+        .into_iter()
+        .filter(|component| seen.insert((component.kind, component.name.clone(), component.version.clone())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_action_reusable_workflow_and_container() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n      - uses: owner/repo/.github/workflows/ci.yml@main\n      - uses: docker://alpine:3.19\n",
+        )
+        .unwrap();
+        let components = self::components(&workflow);
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0].kind, ComponentKind::Action);
+        assert_eq!(components[0].name, "actions/checkout");
+        assert_eq!(components[1].kind, ComponentKind::ReusableWorkflow);
+        assert_eq!(components[2].kind, ComponentKind::ContainerImage);
+        assert_eq!(components[2].name, "alpine");
+        assert_eq!(components[2].version, "3.19");
+    }
+
+    #[test]
+    fn includes_job_level_reusable_workflow_call() {
+        let workflow = Workflow::from_string(
+            "w.yml",
+            "on: push\njobs:\n  j:\n    uses: owner/repo/.github/workflows/reusable.yml@v1\n",
+        )
+        .unwrap();
+        let components = self::components(&workflow);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].kind, ComponentKind::ReusableWorkflow);
+    }
+
+    #[test]
+    fn dedupe_collapses_repeated_dependencies() {
+        let components = vec![
+            Component { kind: ComponentKind::Action, name: "actions/checkout".into(), version: "v4".into() },
+            Component { kind: ComponentKind::Action, name: "actions/checkout".into(), version: "v4".into() },
+        ];
+        assert_eq!(dedupe(components).len(), 1);
+    }
+}
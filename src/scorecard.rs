@@ -0,0 +1,82 @@
+//! Maps zizmor's own rule ids onto the [OpenSSF Scorecard] checks they
+//! overlap with, and rolls findings up into a per-check pass/fail
+//! summary, so organizations already tracking Scorecard can consume
+//! zizmor results directly instead of running a second, differently-
+//! shaped tool.
+//!
+//! [OpenSSF Scorecard]: https://github.com/ossf/scorecard
+
+use serde::Serialize;
+
+use crate::finding::Finding;
+
+/// A Scorecard check name. Only the checks zizmor's rules actually
+/// overlap with are represented here - this isn't a full mirror of
+/// Scorecard's check set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScorecardCheck {
+    TokenPermissions,
+    PinnedDependencies,
+    DangerousWorkflow,
+}
+
+/// The Scorecard check `ident` overlaps with, if any. Rules with no
+/// Scorecard analog (e.g. `invalid-step-reference`) return `None`.
+pub fn check_for(ident: &str) -> Option<ScorecardCheck> {
+    match ident {
+        "excessive-permissions" => Some(ScorecardCheck::TokenPermissions),
+        "unpinned-uses" | "pin-comment-mismatch" | "known-vulnerable-action" => Some(ScorecardCheck::PinnedDependencies),
+        "dangerous-triggers" | "template-injection" | "checkout-persist-credentials" => Some(ScorecardCheck::DangerousWorkflow),
+        _ => None,
+    }
+}
+
+/// A single check's roll-up: whether any finding mapped to it, and how
+/// many.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckSummary {
+    pub check: ScorecardCheck,
+    pub finding_count: usize,
+    pub passing: bool,
+}
+
+/// Summarizes `findings` per Scorecard check. Every check that at least
+/// one rule maps to is reported, even with zero findings, so a passing
+/// run's summary still lists all of Scorecard's covered checks.
+pub fn summarize(findings: &[Finding]) -> Vec<CheckSummary> {
+    let checks = [ScorecardCheck::TokenPermissions, ScorecardCheck::PinnedDependencies, ScorecardCheck::DangerousWorkflow];
+    checks
+        .into_iter()
+        .map(|check| {
+            let finding_count = findings.iter().filter(|f| check_for(f.ident) == Some(check)).count();
+            CheckSummary { check, finding_count, passing: finding_count == 0 }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finding::Severity;
+
+    #[test]
+    fn maps_known_rules() {
+        assert_eq!(check_for("unpinned-uses"), Some(ScorecardCheck::PinnedDependencies));
+        assert_eq!(check_for("excessive-permissions"), Some(ScorecardCheck::TokenPermissions));
+        assert_eq!(check_for("dangerous-triggers"), Some(ScorecardCheck::DangerousWorkflow));
+        assert_eq!(check_for("invalid-step-reference"), None);
+    }
+
+    #[test]
+    fn summarize_counts_per_check_and_reports_zero_findings() {
+        let findings = vec![Finding::new("unpinned-uses", "x").with_severity(Severity::High)];
+        let summary = summarize(&findings);
+        let pinned = summary.iter().find(|s| s.check == ScorecardCheck::PinnedDependencies).unwrap();
+        assert_eq!(pinned.finding_count, 1);
+        assert!(!pinned.passing);
+        let token = summary.iter().find(|s| s.check == ScorecardCheck::TokenPermissions).unwrap();
+        assert_eq!(token.finding_count, 0);
+        assert!(token.passing);
+    }
+}
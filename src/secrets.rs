@@ -0,0 +1,85 @@
+//! Lightweight symbolic tracking of which shell variables in a `run:`
+//! script hold a secret value - either directly from a `secrets.*`
+//! expression, or derived from another variable that already does via
+//! a plain assignment, `export`, or command substitution - so sink
+//! audits can flag a derived variable (`TOKEN2=$TOKEN`) the same way
+//! they flag a direct `${{ secrets.TOKEN }}` reference.
+//!
+//! This is line-based and doesn't parse real shell syntax (see
+//! [`crate::shell`] for that), so it can miss multi-line constructs and
+//! will happily "taint" a variable that's reassigned to something safe
+//! later on the same line it's read from - acceptable for a heuristic
+//! whose false positives just mean an extra look, not a missed finding.
+
+use std::collections::HashSet;
+
+use regex::Regex;
+
+fn assignment_re() -> Regex {
+    // `export? NAME=value`, the two shapes a `run:` script actually
+    // uses to set a shell variable.
+    Regex::new(r"^(?:export\s+)?([A-Za-z_][A-Za-z0-9_]*)=(.*)$").unwrap()
+}
+
+fn expr_re() -> Regex {
+    Regex::new(r"\$\{\{\s*([^}]+?)\s*\}\}").unwrap()
+}
+
+fn var_ref_re() -> Regex {
+    Regex::new(r"\$\{?([A-Za-z_][A-Za-z0-9_]*)\b").unwrap()
+}
+
+fn is_secrets_expr(expr: &str) -> bool {
+    expr.trim_start().starts_with("secrets.")
+}
+
+/// The names of shell variables that `run` assigns a secret-holding
+/// value to, directly or transitively through other such variables.
+pub fn secret_vars_in_script(run: &str) -> HashSet<String> {
+    let mut secret_vars = HashSet::new();
+
+    for line in run.lines() {
+        let Some(captures) = assignment_re().captures(line.trim()) else { continue };
+        let name = captures[1].to_string();
+        let rhs = &captures[2];
+
+        let direct = expr_re().captures_iter(rhs).any(|c| is_secrets_expr(&c[1]));
+        // Catches both a plain `$OTHER` reference and one buried inside
+        // a `$(...)` command substitution, since this just scans the
+        // whole right-hand side for variable names regardless of where
+        // they appear in it.
+        let derived = var_ref_re().captures_iter(rhs).any(|c| secret_vars.contains(&c[1]));
+
+        if direct || derived {
+            secret_vars.insert(name);
+        }
+    }
+
+    secret_vars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_direct_secret_assignment() {
+        let run = "TOKEN=${{ secrets.GH_TOKEN }}\necho \"$TOKEN\"";
+        assert_eq!(secret_vars_in_script(run), HashSet::from(["TOKEN".to_string()]));
+    }
+
+    #[test]
+    fn flags_variable_derived_via_command_substitution() {
+        let run = "export TOKEN=${{ secrets.GH_TOKEN }}\nENCODED=$(base64 <<< \"$TOKEN\")";
+        assert_eq!(
+            secret_vars_in_script(run),
+            HashSet::from(["TOKEN".to_string(), "ENCODED".to_string()])
+        );
+    }
+
+    #[test]
+    fn does_not_flag_unrelated_assignment() {
+        let run = "GREETING=hello\necho \"$GREETING\"";
+        assert!(secret_vars_in_script(run).is_empty());
+    }
+}
@@ -0,0 +1,62 @@
+//! Request-handling core for `zizmor serve`, kept separate from the
+//! actual socket/HTTP-framing code in `bin_support::serve` so it can be
+//! unit-tested without binding a port.
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::finding::Finding;
+use crate::models::Workflow;
+use crate::registry::default_audits;
+
+#[derive(Debug, Deserialize)]
+pub struct AuditRequest {
+    /// A display name for the workflow, e.g. its path within the repo.
+    pub name: String,
+    /// The workflow's raw YAML content.
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditResponse {
+    pub findings: Vec<Finding>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+/// Audits a single posted workflow document and returns its findings.
+///
+/// The audit set and [`Config`] are built once by the caller and passed
+/// in rather than rebuilt per request - that's the "keep caches warm
+/// across requests" part of a server mode that a per-invocation CLI run
+/// can't offer.
+pub fn handle(request: &AuditRequest, audits: &[Box<dyn crate::audit::Audit>], config: &Config) -> anyhow::Result<AuditResponse> {
+    let workflow = Workflow::from_string(request.name.clone(), request.content.clone())?;
+    let findings = crate::run_audits(&[workflow], &[], audits, config)?;
+    Ok(AuditResponse { findings })
+}
+
+/// Builds the audit set and config once, for a server to reuse across
+/// every request it handles for the rest of its process lifetime.
+pub fn warm_state() -> (Vec<Box<dyn crate::audit::Audit>>, Config) {
+    (default_audits(), Config::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_a_posted_workflow() {
+        let (audits, config) = warm_state();
+        let request = AuditRequest {
+            name: "ci.yml".to_string(),
+            content: "on: push\njobs:\n  j:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n".to_string(),
+        };
+        let response = handle(&request, &audits, &config).unwrap();
+        assert!(response.findings.iter().any(|f| f.ident == "unpinned-uses"));
+    }
+}
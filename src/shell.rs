@@ -0,0 +1,157 @@
+//! Shell-aware classification of where an interpolated expression lands
+//! in a `run:` script: a quoted string, bare command position, or an
+//! `eval`-style sink. Distinguishing these cuts false positives compared
+//! to the substring matching [`crate::audit::template_injection`] used
+//! to rely on exclusively.
+
+/// The interpreter a `run:` step actually executes under. Quoting and
+/// injection semantics differ enough between these that a single
+/// bash-shaped heuristic misclassifies PowerShell and `cmd` scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Pwsh,
+    Cmd,
+}
+
+impl Shell {
+    /// Parses a step's `shell:` value (or runner default), falling back
+    /// to [`Shell::Bash`] for shells we don't model specially (`sh`,
+    /// Python, etc.) since its quoting rules are the closest match.
+    pub fn parse(shell: &str) -> Self {
+        match shell {
+            "pwsh" | "powershell" => Shell::Pwsh,
+            "cmd" => Shell::Cmd,
+            _ => Shell::Bash,
+        }
+    }
+}
+
+/// Where an expression's expansion lands once the shell sees it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sink {
+    /// Inside a double-quoted string: still shell-expandable, but not
+    /// subject to word-splitting/globbing.
+    DoubleQuoted,
+    /// Inside a single-quoted string: the shell treats it as a literal,
+    /// so interpolation happens before the shell ever runs, not after.
+    SingleQuoted,
+    /// Bare command position, or an argument to `eval`/`sh -c`: the
+    /// worst case, since the expanded text can introduce new commands.
+    CommandPosition,
+    /// Couldn't determine the context with confidence.
+    Unknown,
+}
+
+/// Classifies where a byte offset within a shell script lands.
+pub trait SinkClassifier {
+    fn classify(&self, script: &str, offset: usize, shell: Shell) -> Sink;
+}
+
+/// Best-effort quote tracking by scanning from the start of the script.
+/// Doesn't understand here-docs, command substitution nesting, or
+/// backslash escapes inside single quotes; good enough to tell "this is
+/// inside a quoted string" from "this is bare" in the common case.
+pub struct HeuristicClassifier;
+
+impl SinkClassifier for HeuristicClassifier {
+    fn classify(&self, script: &str, offset: usize, shell: Shell) -> Sink {
+        // cmd.exe doesn't treat its own metacharacters (`&`, `|`, `>`,
+        // ...) as literal inside `"..."`, so quoting there doesn't buy
+        // the safety it does in bash/pwsh - every position is
+        // effectively a command position.
+        if shell == Shell::Cmd {
+            return Sink::CommandPosition;
+        }
+
+        let mut in_single = false;
+        let mut in_double = false;
+        let mut escaped = false;
+        let escape_char = if shell == Shell::Pwsh { '`' } else { '\\' };
+
+        for ch in script[..offset.min(script.len())].chars() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match ch {
+                c if c == escape_char && !in_single => escaped = true,
+                '\'' if !in_double => in_single = !in_single,
+                '"' if !in_single => in_double = !in_double,
+                _ => {}
+            }
+        }
+
+        if in_single {
+            Sink::SingleQuoted
+        } else if in_double {
+            Sink::DoubleQuoted
+        } else {
+            Sink::CommandPosition
+        }
+    }
+}
+
+/// A real bash-grammar-backed classifier (tree-sitter or similar) is
+/// planned but not yet wired up; this feature flag marks the seam so it
+/// can replace [`HeuristicClassifier`] without touching call sites.
+#[cfg(feature = "shell-grammar")]
+pub struct GrammarClassifier;
+
+#[cfg(feature = "shell-grammar")]
+impl SinkClassifier for GrammarClassifier {
+    fn classify(&self, _script: &str, _offset: usize, _shell: Shell) -> Sink {
+        Sink::Unknown
+    }
+}
+
+pub fn default_classifier() -> Box<dyn SinkClassifier> {
+    #[cfg(feature = "shell-grammar")]
+    {
+        Box::new(GrammarClassifier)
+    }
+    #[cfg(not(feature = "shell-grammar"))]
+    {
+        Box::new(HeuristicClassifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_double_quoted_position() {
+        let script = r#"echo "value is $X""#;
+        let offset = script.find("$X").unwrap();
+        assert_eq!(HeuristicClassifier.classify(script, offset, Shell::Bash), Sink::DoubleQuoted);
+    }
+
+    #[test]
+    fn classifies_single_quoted_position() {
+        let script = r#"echo 'value is $X'"#;
+        let offset = script.find("$X").unwrap();
+        assert_eq!(HeuristicClassifier.classify(script, offset, Shell::Bash), Sink::SingleQuoted);
+    }
+
+    #[test]
+    fn classifies_bare_command_position() {
+        let script = "eval $X";
+        let offset = script.find("$X").unwrap();
+        assert_eq!(HeuristicClassifier.classify(script, offset, Shell::Bash), Sink::CommandPosition);
+    }
+
+    #[test]
+    fn cmd_quoting_never_protects() {
+        let script = r#""%X%""#;
+        let offset = script.find("%X%").unwrap();
+        assert_eq!(HeuristicClassifier.classify(script, offset, Shell::Cmd), Sink::CommandPosition);
+    }
+
+    #[test]
+    fn pwsh_double_quoted_position() {
+        let script = r#"Write-Output "value is $X""#;
+        let offset = script.find("$X").unwrap();
+        assert_eq!(HeuristicClassifier.classify(script, offset, Shell::Pwsh), Sink::DoubleQuoted);
+    }
+}
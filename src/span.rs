@@ -0,0 +1,56 @@
+//! Maps a byte offset within a *decoded* YAML scalar (e.g. the text of a
+//! `run:` block as audits see it) back to a byte range in the document's
+//! raw source, so a [`crate::finding::SymbolicLocation`] can point at the
+//! exact sub-string a finding is about instead of the whole scalar.
+//!
+//! This only handles the case where the decoded scalar appears
+//! byte-for-byte in the raw source - true for plain scalars and literal
+//! (`|`) block scalars, which is the common case for `run:` steps. It
+//! deliberately does not attempt to reconstruct source positions through
+//! folded (`>`) scalars or escaped flow scalars, where the decoded text
+//! diverges from the source and a real fix would need to carry spans
+//! from the YAML parse itself rather than re-deriving them from decoded
+//! text, matching the same best-effort tradeoff already made by
+//! [`crate::fix`]'s `raw.find()`-based fixes.
+
+/// Resolves `inner` (a byte range into `scalar`, the decoded value of
+/// some YAML node) to the equivalent byte range in `source`. Returns
+/// `None` if `scalar` doesn't appear verbatim in `source`, or if
+/// multiple non-overlapping occurrences exist and the match is
+/// therefore ambiguous - callers should fall back to locating the whole
+/// scalar instead.
+pub fn resolve_scalar_span(source: &str, scalar: &str, inner: std::ops::Range<usize>) -> Option<std::ops::Range<usize>> {
+    if scalar.is_empty() || inner.end > scalar.len() {
+        return None;
+    }
+    let mut occurrences = source.match_indices(scalar);
+    let (start, _) = occurrences.next()?;
+    if occurrences.next().is_some() {
+        return None;
+    }
+    Some(start + inner.start..start + inner.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_offset_within_unique_scalar() {
+        let source = "jobs:\n  j:\n    steps:\n      - run: echo \"${{ x }}\"\n";
+        let scalar = "echo \"${{ x }}\"";
+        let resolved = resolve_scalar_span(source, scalar, 6..14).unwrap();
+        assert_eq!(&source[resolved], "${{ x }}");
+    }
+
+    #[test]
+    fn refuses_to_guess_when_scalar_appears_twice() {
+        let source = "a: echo hi\nb: echo hi\n";
+        assert_eq!(resolve_scalar_span(source, "echo hi", 0..4), None);
+    }
+
+    #[test]
+    fn returns_none_when_scalar_is_absent() {
+        assert_eq!(resolve_scalar_span("a: b\n", "missing", 0..3), None);
+    }
+}
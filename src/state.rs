@@ -0,0 +1,15 @@
+//! Shared state threaded through a single `zizmor` invocation.
+
+use crate::config::Config;
+
+/// Context shared across all audits for one run.
+pub struct AuditState {
+    pub config: Config,
+    pub offline: bool,
+}
+
+impl AuditState {
+    pub fn new(config: Config, offline: bool) -> Self {
+        Self { config, offline }
+    }
+}
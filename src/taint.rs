@@ -0,0 +1,118 @@
+//! Knowledge base mapping trigger types to which `github.event.*`
+//! context paths are attacker-controlled under them, shared by every
+//! injection-style audit instead of each hand-rolling its own list.
+
+/// How trustworthy a context path's value is, given a particular trigger.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub enum Taint {
+    /// Fully controlled by the repository/workflow author.
+    Trusted,
+    /// Controlled by whoever opened the PR/issue/comment; untrusted on
+    /// triggers that run with elevated permissions or secrets.
+    AttackerControlled,
+}
+
+/// One (trigger, context path) fact in the knowledge base.
+struct Entry {
+    trigger: &'static str,
+    path: &'static str,
+    taint: Taint,
+}
+
+const TABLE: &[Entry] = &[
+    Entry {
+        trigger: "pull_request_target",
+        path: "github.event.pull_request.title",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "pull_request_target",
+        path: "github.event.pull_request.body",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "pull_request_target",
+        path: "github.event.pull_request.head.ref",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "pull_request",
+        path: "github.event.pull_request.title",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "pull_request",
+        path: "github.event.pull_request.body",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "issues",
+        path: "github.event.issue.title",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "issues",
+        path: "github.event.issue.body",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "issue_comment",
+        path: "github.event.comment.body",
+        taint: Taint::AttackerControlled,
+    },
+    Entry {
+        trigger: "push",
+        path: "github.event.head_commit.message",
+        taint: Taint::AttackerControlled,
+    },
+];
+
+/// Looks up the taint of `context_path` for a workflow, across all of
+/// its triggers: attacker-controlled if *any* trigger makes it so, since
+/// a workflow is as dangerous as its most permissive trigger.
+pub fn taint_of_workflow(workflow: &crate::models::Workflow, context_path: &str) -> Taint {
+    crate::models::trigger_names(&workflow.on)
+        .into_iter()
+        .map(|trigger| taint_of(&trigger, context_path))
+        .max()
+        .unwrap_or(Taint::Trusted)
+}
+
+/// Every context path this knowledge base has an opinion on, for
+/// building reports that enumerate every known fact rather than looking
+/// one path up at a time.
+pub fn known_paths() -> Vec<&'static str> {
+    let mut paths: Vec<&'static str> = TABLE.iter().map(|e| e.path).collect();
+    paths.sort_unstable();
+    paths.dedup();
+    paths
+}
+
+/// Looks up the taint of `context_path` under `trigger`, defaulting to
+/// [`Taint::Trusted`] for anything not explicitly known to be
+/// attacker-influenced (e.g. `github.repository`, `github.sha`).
+pub fn taint_of(trigger: &str, context_path: &str) -> Taint {
+    TABLE
+        .iter()
+        .find(|e| e.trigger == trigger && e.path == context_path)
+        .map(|e| e.taint)
+        .unwrap_or(Taint::Trusted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pr_title_is_attacker_controlled_on_pull_request_target() {
+        assert_eq!(
+            taint_of("pull_request_target", "github.event.pull_request.title"),
+            Taint::AttackerControlled
+        );
+    }
+
+    #[test]
+    fn unknown_path_defaults_to_trusted() {
+        assert_eq!(taint_of("push", "github.sha"), Taint::Trusted);
+    }
+}
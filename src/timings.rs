@@ -0,0 +1,112 @@
+//! Per-audit, per-file wall-time instrumentation for `--timings`.
+//!
+//! Audits that dominate scan time are easy to guess wrong; this module
+//! exists so a maintainer can see it instead, before filing (or being
+//! handed) a performance bug with no data behind it.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// One audit's wall-time on one file.
+#[derive(Debug, Clone)]
+pub struct Timing {
+    pub audit_id: String,
+    pub file: String,
+    pub duration: Duration,
+}
+
+/// Every [`Timing`] recorded during a scan, in recording order.
+#[derive(Debug, Clone, Default)]
+pub struct TimingReport {
+    pub timings: Vec<Timing>,
+}
+
+impl TimingReport {
+    pub fn record(&mut self, audit_id: &str, file: &str, duration: Duration) {
+        self.timings.push(Timing {
+            audit_id: audit_id.to_string(),
+            file: file.to_string(),
+            duration,
+        });
+    }
+
+    /// Total time spent per audit, summed across every file, slowest first.
+    pub fn by_audit(&self) -> Vec<(String, Duration)> {
+        let mut totals: BTreeMap<&str, Duration> = BTreeMap::new();
+        for timing in &self.timings {
+            *totals.entry(timing.audit_id.as_str()).or_default() += timing.duration;
+        }
+        let mut totals: Vec<(String, Duration)> = totals.into_iter().map(|(id, total)| (id.to_string(), total)).collect();
+        totals.sort_by_key(|(_, total)| std::cmp::Reverse(*total));
+        totals
+    }
+
+    /// Total time spent per file, summed across every audit, slowest first.
+    pub fn by_file(&self) -> Vec<(String, Duration)> {
+        let mut totals: BTreeMap<&str, Duration> = BTreeMap::new();
+        for timing in &self.timings {
+            *totals.entry(timing.file.as_str()).or_default() += timing.duration;
+        }
+        let mut totals: Vec<(String, Duration)> = totals.into_iter().map(|(file, total)| (file.to_string(), total)).collect();
+        totals.sort_by_key(|(_, total)| std::cmp::Reverse(*total));
+        totals
+    }
+
+    /// A human-readable table: per-audit totals, then per-file totals.
+    pub fn render_table(&self) -> String {
+        let mut out = String::new();
+        out.push_str("audit                          total\n");
+        for (audit_id, total) in self.by_audit() {
+            out.push_str(&format!("{audit_id:<30} {:>8.3}s\n", total.as_secs_f64()));
+        }
+        out.push('\n');
+        out.push_str("file                           total\n");
+        for (file, total) in self.by_file() {
+            out.push_str(&format!("{file:<30} {:>8.3}s\n", total.as_secs_f64()));
+        }
+        out
+    }
+
+    /// The raw per-(audit, file) timings, as a JSON array.
+    pub fn render_json(&self) -> anyhow::Result<String> {
+        let entries: Vec<serde_json::Value> = self
+            .timings
+            .iter()
+            .map(|timing| {
+                serde_json::json!({
+                    "audit": timing.audit_id,
+                    "file": timing.file,
+                    "seconds": timing.duration.as_secs_f64(),
+                })
+            })
+            .collect();
+        Ok(serde_json::to_string_pretty(&entries)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn by_audit_sums_across_files() {
+        let mut report = TimingReport::default();
+        report.record("unpinned-uses", "a.yml", Duration::from_millis(100));
+        report.record("unpinned-uses", "b.yml", Duration::from_millis(50));
+        report.record("template-injection", "a.yml", Duration::from_millis(10));
+
+        let totals = report.by_audit();
+        assert_eq!(totals[0], ("unpinned-uses".to_string(), Duration::from_millis(150)));
+        assert_eq!(totals[1], ("template-injection".to_string(), Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn render_json_round_trips_fields() {
+        let mut report = TimingReport::default();
+        report.record("unpinned-uses", "a.yml", Duration::from_millis(5));
+        let json = report.render_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["audit"], "unpinned-uses");
+        assert_eq!(value[0]["file"], "a.yml");
+    }
+}
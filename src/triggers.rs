@@ -0,0 +1,88 @@
+//! Normalizes `on:` - a string, a sequence of event names, or a map with
+//! per-event filters - into one shape, so audits that need trigger names
+//! or per-event filters stop each re-implementing the three-way match
+//! over [`serde_yaml::Value`] themselves.
+
+use indexmap::IndexMap;
+
+/// Events that a run under this trigger can be initiated by someone
+/// other than a trusted maintainer - a PR from a fork, an issue comment,
+/// a `workflow_run` chained off one of those, ... - the precondition for
+/// most of zizmor's injection-style findings.
+const FORK_FACING_EVENTS: &[&str] = &[
+    "pull_request",
+    "pull_request_target",
+    "issue_comment",
+    "issues",
+    "discussion",
+    "discussion_comment",
+    "workflow_run",
+];
+
+/// The normalized form of a workflow's `on:` block.
+#[derive(Debug, Clone)]
+pub struct Triggers {
+    filters: IndexMap<String, serde_yaml::Value>,
+}
+
+impl Triggers {
+    pub fn new(on: &serde_yaml::Value) -> Self {
+        let filters = match on {
+            serde_yaml::Value::String(s) => IndexMap::from([(s.clone(), serde_yaml::Value::Null)]),
+            serde_yaml::Value::Sequence(seq) => seq
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| (s.to_string(), serde_yaml::Value::Null))
+                .collect(),
+            serde_yaml::Value::Mapping(map) => map
+                .iter()
+                .filter_map(|(k, v)| k.as_str().map(|k| (k.to_string(), v.clone())))
+                .collect(),
+            _ => IndexMap::new(),
+        };
+        Self { filters }
+    }
+
+    /// The trigger event names, in declaration order.
+    pub fn events(&self) -> Vec<String> {
+        self.filters.keys().cloned().collect()
+    }
+
+    /// The per-event filter (`branches:`, `paths:`, `types:`, ...), if
+    /// `event` is declared on this workflow and was given one.
+    pub fn filters_for(&self, event: &str) -> Option<&serde_yaml::Value> {
+        self.filters.get(event).filter(|v| match v {
+            serde_yaml::Value::Null => false,
+            serde_yaml::Value::Mapping(map) => !map.is_empty(),
+            _ => true,
+        })
+    }
+
+    /// Whether any declared trigger can be initiated by someone other
+    /// than a trusted maintainer.
+    pub fn has_fork_facing_trigger(&self) -> bool {
+        self.filters.keys().any(|e| FORK_FACING_EVENTS.contains(&e.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_bare_string() {
+        let triggers = Triggers::new(&serde_yaml::Value::String("push".into()));
+        assert_eq!(triggers.events(), vec!["push".to_string()]);
+        assert!(!triggers.has_fork_facing_trigger());
+    }
+
+    #[test]
+    fn exposes_per_event_filters() {
+        let on: serde_yaml::Value = serde_yaml::from_str("push:\n  branches: [main]\npull_request: {}\n").unwrap();
+        let triggers = Triggers::new(&on);
+        assert_eq!(triggers.events(), vec!["push".to_string(), "pull_request".to_string()]);
+        assert!(triggers.filters_for("push").is_some());
+        assert!(triggers.filters_for("pull_request").is_none());
+        assert!(triggers.has_fork_facing_trigger());
+    }
+}
@@ -0,0 +1,23 @@
+//! JS-friendly entry point for the `wasm32-unknown-unknown` build, so a
+//! browser playground can audit a pasted workflow entirely
+//! client-side. Only offline-capable audits run here - there's no way
+//! to reach GitHub's API from a sandboxed wasm module without the
+//! embedder wiring up its own `fetch` bridge, which is out of scope for
+//! this first pass (see [`crate::audit::Audit::needs_network`]).
+
+use wasm_bindgen::prelude::*;
+
+use crate::config::Config;
+use crate::models::Workflow;
+use crate::registry::default_audits;
+
+/// Audits a single pasted workflow document and returns its findings as
+/// a JSON array, so a JS caller can render them without linking against
+/// any of zizmor's Rust types directly.
+#[wasm_bindgen]
+pub fn audit_workflow_yaml(name: &str, yaml: &str) -> Result<String, JsValue> {
+    let workflow = Workflow::from_string(name, yaml).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let audits: Vec<_> = default_audits().into_iter().filter(|audit| !audit.needs_network()).collect();
+    let findings = crate::run_audits(&[workflow], &[], &audits, &Config::default()).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    serde_json::to_string(&findings).map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,134 @@
+//! Core logic for a GitHub App webhook receiver: verifying a payload's
+//! `X-Hub-Signature-256`, pulling the repo/ref/commit out of it, and
+//! running the normal audit pipeline against the workflow files at that
+//! commit. Kept separate from the HTTP listener in
+//! `bin_support::webhook_serve` so it can be unit-tested without a
+//! socket.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::config::Config;
+use crate::finding::Finding;
+use crate::models::Workflow;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Verifies a `sha256=<hex>` `X-Hub-Signature-256` header against
+/// `body`, using constant-time comparison (via [`Mac::verify_slice`])
+/// so response timing can't leak the secret.
+pub fn verify_signature(secret: &[u8], body: &[u8], signature_header: &str) -> bool {
+    let Some(hex_sig) = signature_header.strip_prefix("sha256=") else {
+        return false;
+    };
+    let Ok(expected) = hex_decode(hex_sig) else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn hex_decode(hex: &str) -> anyhow::Result<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        anyhow::bail!("odd-length hex string");
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(anyhow::Error::from))
+        .collect()
+}
+
+/// The repo/commit a webhook event's audit run should target, pulled
+/// out of the minimal subset of a `push`/`pull_request` payload zizmor
+/// cares about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventContext {
+    pub repo: String,
+    pub sha: String,
+}
+
+/// Extracts [`EventContext`] from a `push` or `pull_request` webhook
+/// payload. Returns `None` for event types zizmor doesn't act on, or a
+/// payload missing the fields it expects.
+pub fn event_context(event: &str, payload: &serde_json::Value) -> Option<EventContext> {
+    let repo = payload.get("repository")?.get("full_name")?.as_str()?.to_string();
+    let sha = match event {
+        "push" => payload.get("after")?.as_str()?.to_string(),
+        "pull_request" => payload.get("pull_request")?.get("head")?.get("sha")?.as_str()?.to_string(),
+        _ => return None,
+    };
+    Some(EventContext { repo, sha })
+}
+
+/// Fetches the workflow files present at a given commit. Needs a real
+/// GitHub API call (the webhook payload itself carries no file
+/// content), so it's a trait the CLI wires up to a real client when
+/// online - same deferred-network pattern as
+/// [`crate::audit::unpinned_uses::RefResolver`]/[`crate::review::ReviewPoster`].
+pub trait WorkflowFetcher {
+    fn fetch(&self, repo: &str, sha: &str) -> anyhow::Result<Vec<(String, String)>>;
+}
+
+/// Runs `audits` against every workflow `fetcher` returns for the event
+/// in `payload`, or an empty result for event types [`event_context`]
+/// doesn't recognize.
+pub fn handle_event(
+    event: &str,
+    payload: &serde_json::Value,
+    fetcher: &dyn WorkflowFetcher,
+    audits: &[Box<dyn crate::audit::Audit>],
+    config: &Config,
+) -> anyhow::Result<Vec<Finding>> {
+    let Some(context) = event_context(event, payload) else {
+        return Ok(vec![]);
+    };
+    let files = fetcher.fetch(&context.repo, &context.sha)?;
+    let workflows: Vec<Workflow> = files
+        .into_iter()
+        .map(|(path, content)| Workflow::from_string(path, content))
+        .collect::<anyhow::Result<_>>()?;
+    crate::run_audits(&workflows, &[], audits, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_a_correct_signature() {
+        let secret = b"topsecret";
+        let body = b"hello world";
+        let mut mac = HmacSha256::new_from_slice(secret).unwrap();
+        mac.update(body);
+        let sig = hex_encode(&mac.finalize().into_bytes());
+        assert!(verify_signature(secret, body, &format!("sha256={sig}")));
+    }
+
+    #[test]
+    fn rejects_a_tampered_body() {
+        let secret = b"topsecret";
+        let mut mac = HmacSha256::new_from_slice(secret).unwrap();
+        mac.update(b"hello world");
+        let sig = hex_encode(&mac.finalize().into_bytes());
+        assert!(!verify_signature(secret, b"goodbye world", &format!("sha256={sig}")));
+    }
+
+    #[test]
+    fn extracts_push_context() {
+        let payload: serde_json::Value = serde_json::from_str(
+            r#"{"repository": {"full_name": "octo/repo"}, "after": "abc123"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            event_context("push", &payload),
+            Some(EventContext { repo: "octo/repo".to_string(), sha: "abc123".to_string() })
+        );
+    }
+
+    fn hex_encode(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}